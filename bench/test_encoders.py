@@ -1,4 +1,5 @@
 import enum
+import json
 import uuid
 from dataclasses import dataclass
 from datetime import date, datetime, time
@@ -269,3 +270,32 @@ def test_load_union(bench_or_check_refcount):
     data = {'foo': 1}
     bench_or_check_refcount.group = 'union'
     bench_or_check_refcount(repeat(lambda: serializer.load(data)))
+
+
+@dataclass
+class JsonItem:
+    id: int
+    name: str
+    created_at: datetime
+    tags: list[str]
+    price: Optional[Decimal] = None
+
+
+JSON_ITEMS = json.dumps(
+    [
+        {'id': i, 'name': f'item {i}', 'created_at': '2024-01-01T00:00:00', 'tags': ['a', 'b'], 'price': '1.5'}
+        for i in range(100)
+    ]
+).encode()
+
+
+def test_load_json(bench_or_check_refcount):
+    serializer = Serializer(list[JsonItem])
+    bench_or_check_refcount.group = 'load_json'
+    bench_or_check_refcount(repeat(lambda: serializer.load_json(JSON_ITEMS), count=100))
+
+
+def test_json_loads_and_load(bench_or_check_refcount):
+    serializer = Serializer(list[JsonItem])
+    bench_or_check_refcount.group = 'load_json'
+    bench_or_check_refcount(repeat(lambda: serializer.load(json.loads(JSON_ITEMS)), count=100))
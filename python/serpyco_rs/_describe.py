@@ -1,7 +1,8 @@
 import dataclasses
 import sys
-from collections.abc import Callable, Iterable, Mapping, Sequence
-from datetime import date, datetime, time
+from collections.abc import Callable, Iterable, Mapping, MutableSet, Sequence
+from collections.abc import Set as AbstractSet
+from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from enum import Enum, IntEnum
 from functools import cache
@@ -30,6 +31,7 @@ from ._impl import (
     BaseType,
     BooleanType,
     BytesType,
+    CoercionType,
     CustomEncoder,
     CustomType,
     DateTimeType,
@@ -46,31 +48,63 @@ from ._impl import (
     LiteralType,
     OptionalType,
     RecursionHolder,
+    SetType,
     StringType,
+    TimeDeltaType,
     TimeType,
     TupleType,
     TypedDictType,
     UnionType,
     UUIDType,
+    ValidatedType,
+    VarTupleType,
 )
 from ._meta import Meta, MetaStateKey
 from ._type_utils import get_type_hints  # type: ignore[attr-defined]
 from ._utils import get_attributes_doc, to_camelcase
 from .metadata import (
     Alias,
+    Coercion,
+    DateFormat,
+    DecimalAsString,
+    DecimalFormat,
+    DecimalPlaces,
     Discriminator,
+    DuplicateItems,
+    EntityInit,
+    ExtraFieldsMarker,
+    ExtraKeys,
+    FieldAccess,
     FieldFormat,
+    ForbidExtra,
     Format,
+    IgnoreExtra,
+    InitMode,
+    IsoDuration,
+    ItemsOrder,
     KeepDefaultForOptional,
     KeepNone,
+    LeftToRight,
     Max,
+    MaxDigits,
     MaxLength,
+    MergeDuplicates,
     Min,
     MinLength,
     NoFormat,
     NoneAsDefaultForOptional,
     NoneFormat,
     OmitNone,
+    Pattern,
+    SkipInit,
+    StringFormat,
+    Tag,
+    TimeDeltaFormat,
+    Timestamp,
+    TimezonePolicy,
+    UnionMode,
+    UnsortedItems,
+    Validator,
 )
 
 
@@ -100,6 +134,25 @@ def describe_type(
     t: Any,
     meta: Optional[Meta] = None,
     custom_type_resolver: Optional[Callable[[Any], Optional[CustomTypeMeta[Any, Any]]]] = None,
+) -> BaseType:
+    type_info = _describe_type(t, meta, custom_type_resolver)
+    metadata = _get_annotated_metadata(t)
+    if validators := [ann.func for ann in metadata if isinstance(ann, Validator)]:
+        if isinstance(type_info, OptionalType):
+            # None is not validated, the same as for `Optional[Annotated[T, Validator(...)]]`
+            inner = ValidatedType(inner=type_info.inner, validators=validators, custom_encoder=None)
+            type_info = OptionalType(inner=inner, custom_encoder=type_info.custom_encoder)
+        else:
+            type_info = ValidatedType(inner=type_info, validators=validators, custom_encoder=None)
+    if coercion := _find_metadata(metadata, Coercion):
+        type_info = CoercionType(inner=type_info, mode=coercion.mode.value, custom_encoder=None)
+    return type_info
+
+
+def _describe_type(
+    t: Any,
+    meta: Optional[Meta],
+    custom_type_resolver: Optional[Callable[[Any], Optional[CustomTypeMeta[Any, Any]]]],
 ) -> BaseType:
     args: tuple[Any, ...] = ()
     metadata = _get_annotated_metadata(t)
@@ -128,19 +181,27 @@ def describe_type(
     filed_format = _find_metadata(metadata, FieldFormat, NoFormat)
     none_format = _find_metadata(metadata, NoneFormat, KeepNone)
     none_as_default_for_optional = _find_metadata(metadata, NoneAsDefaultForOptional, KeepDefaultForOptional)
+    extra_keys = _find_metadata(metadata, ExtraKeys, IgnoreExtra)
+    entity_init = _find_metadata(metadata, EntityInit, SkipInit)
     custom_encoder = _find_metadata(metadata, CustomEncoder)
-    annotation_wrapper = _wrap_annotated([filed_format, none_format, none_as_default_for_optional])
+    annotation_wrapper = _wrap_annotated(
+        [filed_format, none_format, none_as_default_for_optional, extra_keys, entity_init]
+    )
 
     meta_key = MetaStateKey(
         cls=original_t,
         field_format=filed_format,
         none_format=none_format,
         none_as_default_for_optional=none_as_default_for_optional,
+        extra_keys=extra_keys,
+        entity_init=entity_init,
     )
 
     if meta.has_in_state(meta_key):
         return RecursionHolder(
-            name=_generate_name(original_t, filed_format, none_format, none_as_default_for_optional),
+            name=_generate_name(
+                original_t, filed_format, none_format, none_as_default_for_optional, extra_keys, entity_init
+            ),
             state_key=meta_key,
             meta=meta,
             custom_encoder=None,
@@ -161,9 +222,6 @@ def describe_type(
         simple_type_mapping: Mapping[type, type[BaseType]] = {
             bytes: BytesType,
             bool: BooleanType,
-            date: DateType,
-            time: TimeType,
-            datetime: DateTimeType,
             UUID: UUIDType,
         }
 
@@ -187,18 +245,59 @@ def describe_type(
         if t is Decimal:
             min_meta = _find_metadata(metadata, Min)
             max_meta = _find_metadata(metadata, Max)
+            max_digits = _find_metadata(metadata, MaxDigits)
+            decimal_places = _find_metadata(metadata, DecimalPlaces)
+            decimal_format = _find_metadata(metadata, DecimalFormat, DecimalAsString)
             return DecimalType(
                 min=min_meta.value if min_meta else None,
                 max=max_meta.value if max_meta else None,
+                max_digits=max_digits.value if max_digits else None,
+                decimal_places=decimal_places.value if decimal_places else None,
+                rounding=decimal_places.rounding if decimal_places else None,
+                dump_as_number=decimal_format.as_number,
+                custom_encoder=custom_encoder,
+            )
+
+        date_format = _find_metadata(metadata, DateFormat)
+
+        if t is date:
+            timestamp = _find_metadata(metadata, Timestamp)
+            return DateType(
+                format=date_format.value if date_format else None,
+                timestamp_unit=timestamp.unit if timestamp else None,
+                timestamp_as_float=timestamp.as_float if timestamp else False,
+                custom_encoder=custom_encoder,
+            )
+
+        if t is time:
+            return TimeType(format=date_format.value if date_format else None, custom_encoder=custom_encoder)
+
+        if t is datetime:
+            timestamp = _find_metadata(metadata, Timestamp)
+            tz_policy = _find_metadata(metadata, TimezonePolicy)
+            return DateTimeType(
+                format=date_format.value if date_format else None,
+                timestamp_unit=timestamp.unit if timestamp else None,
+                timestamp_as_float=timestamp.as_float if timestamp else False,
+                tz_policy=tz_policy.policy.value if tz_policy else None,
+                timezone=tz_policy.timezone if tz_policy else None,
                 custom_encoder=custom_encoder,
             )
 
+        if t is timedelta:
+            timedelta_format = _find_metadata(metadata, TimeDeltaFormat, IsoDuration)
+            return TimeDeltaType(format=timedelta_format.format.value, custom_encoder=custom_encoder)
+
         if t is str:
             min_length_meta = _find_metadata(metadata, MinLength)
             max_length_meta = _find_metadata(metadata, MaxLength)
+            pattern_meta = _find_metadata(metadata, Pattern)
+            format_meta = _find_metadata(metadata, StringFormat)
             return StringType(
                 min_length=min_length_meta.value if min_length_meta else None,
                 max_length=max_length_meta.value if max_length_meta else None,
+                pattern=pattern_meta.value if pattern_meta else None,
+                format=format_meta.value if format_meta else None,
                 custom_encoder=custom_encoder,
             )
 
@@ -216,6 +315,21 @@ def describe_type(
                 custom_encoder=custom_encoder,
             )
 
+        if t in {set, frozenset, AbstractSet, MutableSet}:
+            duplicate_items = _find_metadata(metadata, DuplicateItems, MergeDuplicates)
+            items_order = _find_metadata(metadata, ItemsOrder, UnsortedItems)
+            return SetType(
+                item_type=(
+                    describe_type(annotation_wrapper(args[0]), meta, custom_type_resolver)
+                    if args
+                    else AnyType(custom_encoder=None)
+                ),
+                frozen=t in {frozenset, AbstractSet},
+                forbid_duplicates=duplicate_items.forbid,
+                sort_on_dump=items_order.sort,
+                custom_encoder=custom_encoder,
+            )
+
         if t in {Mapping, dict}:
             return DictionaryType(
                 key_type=(
@@ -233,8 +347,21 @@ def describe_type(
             )
 
         if t is tuple:
-            if not args or Ellipsis in args:
-                raise RuntimeError('Variable length tuples are not supported')
+            if not args or (len(args) == 2 and args[1] is Ellipsis):
+                min_length_meta = _find_metadata(metadata, MinLength)
+                max_length_meta = _find_metadata(metadata, MaxLength)
+                return VarTupleType(
+                    item_type=(
+                        describe_type(annotation_wrapper(args[0]), meta, custom_type_resolver)
+                        if args
+                        else AnyType(custom_encoder=None)
+                    ),
+                    min_length=min_length_meta.value if min_length_meta else None,
+                    max_length=max_length_meta.value if max_length_meta else None,
+                    custom_encoder=custom_encoder,
+                )
+            if Ellipsis in args:
+                raise RuntimeError(f'Invalid tuple annotation: {original_t}')
             return TupleType(
                 item_types=[describe_type(annotation_wrapper(arg), meta, custom_type_resolver) for arg in args],
                 custom_encoder=custom_encoder,
@@ -252,6 +379,8 @@ def describe_type(
                 cls_none_format=none_format,
                 custom_encoder=custom_encoder,
                 cls_none_as_default_for_optional=none_as_default_for_optional,
+                cls_extra_keys=extra_keys,
+                cls_entity_init=entity_init,
                 meta=meta,
                 custom_type_resolver=custom_type_resolver,
             )
@@ -280,24 +409,60 @@ def describe_type(
             return UnionType(
                 item_types=[describe_type(annotation_wrapper(arg), meta, custom_type_resolver) for arg in args],
                 union_repr=type_repr.removeprefix('typing.'),
+                smart=_find_metadata(metadata, UnionMode, LeftToRight).smart,
                 custom_encoder=custom_encoder,
             )
 
-        if not all(dataclasses.is_dataclass(arg) or _is_attrs(arg) for arg in args):
+        fallback = discriminator.fallback
+        variants = [arg for arg in args if arg is not fallback]
+        name = discriminator.name
+        # Callable discriminators don't look into the values, so variants can be of any type
+        if isinstance(name, str) and not all(
+            dataclasses.is_dataclass(variant := _unwrap_annotated(arg))
+            or _is_attrs(variant)
+            or is_typeddict(variant)
+            or (custom_type_resolver and custom_type_resolver(variant))
+            for arg in variants
+        ):
             raise RuntimeError(
-                f'Unions supported only for dataclasses or attrs. Provided: {t}[{",".join(map(str, args))}]'
+                'Unions supported only for dataclasses, attrs, TypedDict or custom types. '
+                f'Provided: {t}[{",".join(map(str, args))}]'
             )
 
-        meta = dataclasses.replace(meta, discriminator_field=discriminator.name)
+        variants_meta = dataclasses.replace(meta, discriminator_field=name) if isinstance(name, str) else meta
+        item_types = {}
+        for arg in variants:
+            if tag := _find_metadata(_get_annotated_metadata(arg), Tag):
+                key = tag.value.value if isinstance(tag.value, Enum) else tag.value
+            elif isinstance(name, str):
+                key = _get_discriminator_value(_unwrap_annotated(arg), name)
+            else:
+                raise RuntimeError(f'Variants of unions with callable discriminator must have Tag. Provided: {arg}')
+            # `True == 1`, so such bool and int tags would share the same item too
+            if key in item_types:
+                raise RuntimeError(f'Duplicate discriminator value "{key}" in {t}')
+            item_types[key] = describe_type(annotation_wrapper(arg), variants_meta, custom_type_resolver)
+
+        default_tag = discriminator.default_tag
+        if isinstance(default_tag, Enum):
+            default_tag = default_tag.value
+        if default_tag is not None and not _has_discriminator_key(item_types, default_tag):
+            raise RuntimeError(f'Default discriminator value "{default_tag}" does not match any type of {t}')
+
         return DiscriminatedUnionType(
-            item_types={
-                _get_discriminator_value(arg, discriminator.name): describe_type(
-                    annotation_wrapper(arg), meta, custom_type_resolver
-                )
-                for arg in args
-            },
-            dump_discriminator=discriminator.name,
-            load_discriminator=_apply_format(filed_format, discriminator.name),
+            item_types=item_types,
+            dump_discriminator=discriminator.dump or name,
+            load_discriminator=(
+                '.'.join(_apply_format(filed_format, part) for part in name.split('.'))
+                if isinstance(name, str)
+                else name
+            ),
+            default_tag=default_tag,
+            fallback=(
+                describe_type(annotation_wrapper(fallback), meta, custom_type_resolver)
+                if fallback is not None
+                else None
+            ),
             custom_encoder=custom_encoder,
         )
 
@@ -313,6 +478,8 @@ class _Field(Generic[_T]):
     type: type[_T]
     default: Union[DefaultValue[_T], DefaultValue[None]] = NOT_SET
     default_factory: Union[DefaultValue[Callable[[], _T]], DefaultValue[None]] = NOT_SET
+    init: bool = True
+    init_alias: Optional[str] = None
 
 
 def _describe_entity(
@@ -321,6 +488,8 @@ def _describe_entity(
     cls_filed_format: FieldFormat,
     cls_none_format: NoneFormat,
     cls_none_as_default_for_optional: NoneAsDefaultForOptional,
+    cls_extra_keys: ExtraKeys,
+    cls_entity_init: EntityInit,
     custom_encoder: Optional[CustomEncoder[Any, Any]],
     meta: Meta,
     custom_type_resolver: Optional[Callable[[Any], Optional[CustomTypeMeta[Any, Any]]]],
@@ -339,21 +508,37 @@ def _describe_entity(
     meta = dataclasses.replace(meta, globals=_get_globals(t), discriminator_field=None)
 
     fields = []
+    has_extra_fields = False
     for field in _get_entity_fields(t):
         type_ = types.get(field.name, field.type)
-        type_ = Annotated[type_, cls_filed_format, cls_none_format, cls_none_as_default_for_optional]
+        type_ = Annotated[
+            type_, cls_filed_format, cls_none_format, cls_none_as_default_for_optional, cls_extra_keys, cls_entity_init
+        ]
 
         metadata = _get_annotated_metadata(type_)
         field_type = describe_type(type_, meta, custom_type_resolver)
+        unwrapped_field_type = field_type
+        while isinstance(unwrapped_field_type, (ValidatedType, CoercionType)):
+            unwrapped_field_type = unwrapped_field_type.inner
         alias = _find_metadata(metadata, Alias)
         none_as_default_for_optional = _find_metadata(metadata, NoneAsDefaultForOptional)
+        is_extra_fields = _find_metadata(metadata, ExtraFieldsMarker) is not None
+        if is_extra_fields:
+            if has_extra_fields:
+                raise RuntimeError(f'Only one ExtraFields field is allowed. Provided: {t}')
+            if not (
+                isinstance(unwrapped_field_type, DictionaryType)
+                and isinstance(unwrapped_field_type.key_type, StringType)
+            ):
+                raise RuntimeError(f'ExtraFields field must be dict[str, ...]. Provided: {field.name}: {field.type}')
+            has_extra_fields = True
 
         is_discriminator_field = field.name == discriminator_field
         required = not (field.default != NOT_SET or field.default_factory != NOT_SET) or is_discriminator_field
 
         default = field.default
         if (
-            isinstance(field_type, OptionalType)
+            isinstance(unwrapped_field_type, OptionalType)
             and required
             and none_as_default_for_optional
             and none_as_default_for_optional.use
@@ -361,6 +546,11 @@ def _describe_entity(
             default = DefaultValue.some(None)
             required = False
 
+        access = _find_metadata(metadata, FieldAccess)
+        read_only = access is not None and not access.load
+        if read_only and not is_typeddict(t) and default == NOT_SET and field.default_factory == NOT_SET:
+            raise RuntimeError(f'ReadOnly field must have a default value. Provided: {field.name}: {field.type}')
+
         fields.append(
             EntityField(
                 name=field.name,
@@ -370,24 +560,49 @@ def _describe_entity(
                 default=default,
                 default_factory=field.default_factory,
                 is_discriminator_field=is_discriminator_field,
+                is_extra_fields=is_extra_fields,
+                read_only=read_only,
+                write_only=access is not None and not access.dump,
+                init=field.init,
+                init_alias=field.init_alias,
                 required=required,
             )
         )
 
     if is_typeddict(t):
         return TypedDictType(
-            name=_generate_name(original_t, cls_filed_format, cls_none_format, cls_none_as_default_for_optional),
+            name=_generate_name(
+                original_t,
+                cls_filed_format,
+                cls_none_format,
+                cls_none_as_default_for_optional,
+                cls_extra_keys,
+                cls_entity_init,
+            ),
             fields=fields,
             omit_none=cls_none_format is OmitNone,
+            forbid_extra=cls_extra_keys is ForbidExtra,
             doc=t.__doc__,
             custom_encoder=custom_encoder,
         )
 
     return EntityType(
         cls=t,
-        name=_generate_name(original_t, cls_filed_format, cls_none_format, cls_none_as_default_for_optional),
+        name=_generate_name(
+            original_t,
+            cls_filed_format,
+            cls_none_format,
+            cls_none_as_default_for_optional,
+            cls_extra_keys,
+            cls_entity_init,
+        ),
         fields=fields,
         omit_none=cls_none_format is OmitNone,
+        forbid_extra=cls_extra_keys is ForbidExtra,
+        call_post_init=cls_entity_init.mode is InitMode.post_init and hasattr(t, '__post_init__'),
+        use_constructor=cls_entity_init.mode is InitMode.constructor,
+        post_init_args=_get_init_var_defaults(t) if cls_entity_init.mode is not InitMode.skip else (),
+        validators=list(getattr(t, '__serpyco_validators__', ())),
         is_frozen=_is_frozen_dataclass(t, fields[0]) if fields else False,
         doc=_get_dataclass_doc(t),
         custom_encoder=custom_encoder,
@@ -404,6 +619,7 @@ def _get_entity_fields(t: Any) -> Sequence[_Field[Any]]:
                 default_factory=(
                     DefaultValue.some(f.default_factory) if f.default_factory is not dataclasses.MISSING else NOT_SET
                 ),
+                init=f.init,
             )
             for f in dataclasses.fields(t)
         ]
@@ -435,6 +651,8 @@ def _get_entity_fields(t: Any) -> Sequence[_Field[Any]]:
                     if isinstance(f.default, attr.Factory)  # type: ignore[arg-type]
                     else NOT_SET
                 ),
+                init=f.init,
+                init_alias=_get_attrs_init_alias(f),
             )
             for f in attr.fields(t)
         ]
@@ -463,6 +681,12 @@ def _wrap_annotated(annotations: Iterable[Any]) -> Callable[[_T], _T]:
     return inner
 
 
+def _unwrap_annotated(t: Any) -> Any:
+    if get_origin(t) == Annotated:
+        return get_args(t)[0]
+    return t
+
+
 def _get_annotated_metadata(t: Any) -> tuple[Any, ...]:
     if get_origin(t) == Annotated:
         return getattr(t, '__metadata__', ())
@@ -486,6 +710,8 @@ def _generate_name(
     field_format: FieldFormat,
     none_format: NoneFormat,
     cls_none_as_default_for_optional: NoneAsDefaultForOptional,
+    extra_keys: ExtraKeys,
+    entity_init: EntityInit,
 ) -> str:
     """
     Generate unique name for entity type.
@@ -515,30 +741,49 @@ def _evaluate_forwardref(t: type[_T], meta: Meta) -> type[_T]:
     return t._evaluate(meta.globals, {}, recursive_guard=set())
 
 
-def _get_discriminator_value(t: Any, name: str) -> str:
-    fields = attr.fields(t) if attr and _is_attrs(t) else dataclasses.fields(t)
-    for field in fields:
-        if field.name == name:
-            if _is_literal_type(field.type):
-                args = get_args(field.type)
-                if len(args) != 1:
-                    raise RuntimeError(
-                        f'Type {t} has invalid discriminator field "{name}". '
-                        f'Discriminator supports only Literal[...] with one argument.'
-                    )
-                arg = args[0]
+def _has_discriminator_key(keys: Iterable[Any], key: Any) -> bool:
+    """Bools are not matched with ints."""
+    return any(k == key and type(k) is type(key) for k in keys)
+
 
-                if isinstance(arg, Enum):
-                    arg = arg.value
+def _get_discriminator_value(t: Any, name: str) -> Union[str, int, bool]:
+    """Returns the discriminator value of the type, `name` can be a dotted path to a nested field."""
+    name, _, nested_name = name.partition('.')
+    if dataclasses.is_dataclass(t) or _is_attrs(t):
+        fields = attr.fields(t) if attr and _is_attrs(t) else dataclasses.fields(t)
+        field_types = {field.name: field.type for field in fields}
+    else:
+        try:
+            field_types = get_type_hints(t)
+        except Exception:  # pylint: disable=broad-except
+            field_types = {}
 
-                if isinstance(arg, str):
-                    return arg
+    if name not in field_types:
+        raise RuntimeError(f'Type {t} does not have discriminator field "{name}"')
+    field_type = field_types[name]
 
+    if nested_name:
+        return _get_discriminator_value(field_type, nested_name)
+
+    if _is_literal_type(field_type):
+        args = get_args(field_type)
+        if len(args) != 1:
             raise RuntimeError(
-                f'Type {t} has invalid discriminator field "{name}" with type "{field.type!r}". '
-                f'Discriminator supports Literal[<str>], Literal[Enum] with str values.'
+                f'Type {t} has invalid discriminator field "{name}". '
+                f'Discriminator supports only Literal[...] with one argument.'
             )
-    raise RuntimeError(f'Type {t} does not have discriminator field "{name}"')
+        arg = args[0]
+
+        if isinstance(arg, Enum):
+            arg = arg.value
+
+        if isinstance(arg, (str, int)):
+            return arg
+
+    raise RuntimeError(
+        f'Type {t} has invalid discriminator field "{name}" with type "{field_type!r}". '
+        f'Discriminator supports Literal[<str>], Literal[<int>], Literal[<bool>], Literal[Enum] with str or int values.'
+    )
 
 
 def _is_str_literal(t: Any) -> bool:
@@ -553,6 +798,27 @@ def _is_literal_type(t: Any) -> bool:
     return t is Literal or get_origin(t) is Literal
 
 
+def _get_attrs_init_alias(f: Any) -> Optional[str]:
+    """attrs strips leading underscores of private attributes in `__init__` arguments."""
+    alias = getattr(f, 'alias', None) or f.name.lstrip('_')
+    return alias if alias != f.name else None
+
+
+def _get_init_var_defaults(t: Any) -> tuple[Any, ...]:
+    """Values of `InitVar` pseudo-fields, they are not loaded, so the defaults are used."""
+    if not dataclasses.is_dataclass(t):
+        return ()
+    init_vars = [
+        f
+        for f in t.__dataclass_fields__.values()
+        if f._field_type is dataclasses._FIELD_INITVAR  # type: ignore[attr-defined]  # pylint: disable=protected-access
+    ]
+    for f in init_vars:
+        if f.default is dataclasses.MISSING:
+            raise RuntimeError(f'InitVar field must have a default value. Provided: {f.name}: {f.type}')
+    return tuple(f.default for f in init_vars)
+
+
 def _is_attrs(t: Any) -> bool:
     return attr is not None and attr.has(t)
 
@@ -6,6 +6,7 @@ from ._serpyco_rs import (
     BaseType,
     BooleanType,
     BytesType,
+    CoercionType,
     CustomEncoder as _CustomEncoder,
     DateTimeType,
     DateType,
@@ -24,12 +25,16 @@ from ._serpyco_rs import (
     RecursionHolder,
     SchemaValidationError,
     Serializer,
+    SetType,
     StringType,
+    TimeDeltaType,
     TimeType,
     TupleType,
     TypedDictType,
     UnionType,
     UUIDType,
+    ValidatedType,
+    VarTupleType,
     ValidationError,
     CustomType,
 )
@@ -1,6 +1,7 @@
 from collections.abc import Sequence
+from decimal import Decimal
 from enum import Enum, IntEnum
-from typing import Any, Callable, Generic, TypeVar
+from typing import Any, Callable, Generic, Literal, TypeVar
 
 from ._meta import Meta, MetaStateKey
 
@@ -21,9 +22,11 @@ class SchemaValidationError(ValidationError):
     errors: list[ErrorItem]
 
 class Serializer(Generic[_T]):
-    def __init__(self, py_class: BaseType, naive_datetime_to_utc: bool): ...
+    def __init__(self, py_class: BaseType, naive_datetime_to_utc: bool, collect_errors: bool = False): ...
     def dump(self, value: _T) -> Any: ...
+    def dump_json(self, value: _T) -> bytes: ...
     def load(self, data: Any) -> _T: ...
+    def load_json(self, data: bytes | str) -> _T: ...
     def load_query_params(self, data: Any) -> _T: ...
 
 class CustomEncoder(Generic[_I, _O]):
@@ -52,19 +55,36 @@ class FloatType(BaseType):
     def __init__(self, min: float | None, max: float | None, custom_encoder: CustomEncoder[Any, Any] | None): ...
 
 class DecimalType(BaseType):
-    min: float | None
-    max: float | None
+    min: int | float | Decimal | None
+    max: int | float | Decimal | None
+    max_digits: int | None
+    decimal_places: int | None
+    rounding: str | None
+    dump_as_number: bool
 
-    def __init__(self, min: float | None, max: float | None, custom_encoder: CustomEncoder[Any, Any] | None): ...
+    def __init__(
+        self,
+        min: int | float | Decimal | None = None,
+        max: int | float | Decimal | None = None,
+        max_digits: int | None = None,
+        decimal_places: int | None = None,
+        rounding: str | None = None,
+        dump_as_number: bool = False,
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
 
 class StringType(BaseType):
     min_length: int | None
     max_length: int | None
+    pattern: str | None
+    format: str | None
 
     def __init__(
         self,
         min_length: int | None = None,
         max_length: int | None = None,
+        pattern: str | None = None,
+        format: str | None = None,
         custom_encoder: CustomEncoder[Any, Any] | None = None,
     ): ...
 
@@ -75,13 +95,48 @@ class UUIDType(BaseType):
     def __init__(self, custom_encoder: CustomEncoder[Any, Any] | None): ...
 
 class TimeType(BaseType):
-    def __init__(self, custom_encoder: CustomEncoder[Any, Any] | None): ...
+    format: str | None
+
+    def __init__(self, format: str | None = None, custom_encoder: CustomEncoder[Any, Any] | None = None): ...
 
 class DateTimeType(BaseType):
-    def __init__(self, custom_encoder: CustomEncoder[Any, Any] | None): ...
+    format: str | None
+    timestamp_unit: Literal['s', 'ms', 'us'] | None
+    timestamp_as_float: bool
+    tz_policy: Literal['require_aware', 'require_naive', 'convert_to_utc', 'assume_timezone'] | None
+    timezone: str | None
+
+    def __init__(
+        self,
+        format: str | None = None,
+        timestamp_unit: Literal['s', 'ms', 'us'] | None = None,
+        timestamp_as_float: bool = False,
+        tz_policy: Literal['require_aware', 'require_naive', 'convert_to_utc', 'assume_timezone'] | None = None,
+        timezone: str | None = None,
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
+
+class TimeDeltaType(BaseType):
+    format: Literal['iso', 'seconds', 'int_seconds']
+
+    def __init__(
+        self,
+        format: Literal['iso', 'seconds', 'int_seconds'] = 'iso',
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
 
 class DateType(BaseType):
-    def __init__(self, custom_encoder: CustomEncoder[Any, Any] | None): ...
+    format: str | None
+    timestamp_unit: Literal['s', 'ms', 'us'] | None
+    timestamp_as_float: bool
+
+    def __init__(
+        self,
+        format: str | None = None,
+        timestamp_unit: Literal['s', 'ms', 'us'] | None = None,
+        timestamp_as_float: bool = False,
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
 
 class DefaultValue(Generic[_T]):
     @staticmethod
@@ -98,6 +153,11 @@ class EntityField(BaseType):
     field_type: BaseType
     required: bool = True
     is_discriminator_field: bool = False
+    is_extra_fields: bool = False
+    read_only: bool = False
+    write_only: bool = False
+    init: bool = True
+    init_alias: str | None = None
     default: DefaultValue[Any]
     default_factory: DefaultValue[Callable[[], Any]]
     doc: str | None
@@ -109,6 +169,11 @@ class EntityField(BaseType):
         field_type: BaseType,
         required: bool = True,
         is_discriminator_field: bool = False,
+        is_extra_fields: bool = False,
+        read_only: bool = False,
+        write_only: bool = False,
+        init: bool = True,
+        init_alias: str | None = None,
         default: DefaultValue[Any] = ...,
         default_factory: DefaultValue[Callable[[], Any]] | DefaultValue[None] = ...,
         doc: str | None = None,
@@ -120,6 +185,11 @@ class EntityType(BaseType):
     fields: Sequence[EntityField]
     omit_none: bool
     is_frozen: bool
+    forbid_extra: bool
+    call_post_init: bool
+    use_constructor: bool
+    post_init_args: tuple[Any, ...]
+    validators: list[Callable[[Any], None]]
     doc: str | None
 
     def __init__(
@@ -129,6 +199,11 @@ class EntityType(BaseType):
         fields: Sequence[EntityField],
         omit_none: bool = False,
         is_frozen: bool = False,
+        forbid_extra: bool = False,
+        call_post_init: bool = False,
+        use_constructor: bool = False,
+        post_init_args: tuple[Any, ...] | None = None,
+        validators: list[Callable[[Any], None]] = ...,
         doc: str | None = None,
         custom_encoder: CustomEncoder[Any, Any] | None = None,
     ): ...
@@ -137,6 +212,7 @@ class TypedDictType(BaseType):
     name: str
     fields: Sequence[EntityField]
     omit_none: bool
+    forbid_extra: bool
     doc: str | None
 
     def __init__(
@@ -144,6 +220,7 @@ class TypedDictType(BaseType):
         name: str,
         fields: Sequence[EntityField],
         omit_none: bool = False,
+        forbid_extra: bool = False,
         doc: str | None = None,
         custom_encoder: CustomEncoder[Any, Any] | None = None,
     ): ...
@@ -174,6 +251,28 @@ class OptionalType(BaseType):
 
     def __init__(self, inner: BaseType, custom_encoder: CustomEncoder[Any, Any] | None = None): ...
 
+class ValidatedType(BaseType):
+    inner: BaseType
+    validators: list[Callable[[Any], None]]
+
+    def __init__(
+        self,
+        inner: BaseType,
+        validators: list[Callable[[Any], None]],
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
+
+class CoercionType(BaseType):
+    inner: BaseType
+    mode: Literal['default', 'strict', 'lax']
+
+    def __init__(
+        self,
+        inner: BaseType,
+        mode: Literal['default', 'strict', 'lax'],
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
+
 class DictionaryType(BaseType):
     key_type: BaseType
     value_type: BaseType
@@ -192,6 +291,34 @@ class TupleType(BaseType):
 
     def __init__(self, item_types: list[BaseType], custom_encoder: CustomEncoder[Any, Any] | None = None): ...
 
+class VarTupleType(BaseType):
+    item_type: BaseType
+    min_length: int | None
+    max_length: int | None
+
+    def __init__(
+        self,
+        item_type: BaseType,
+        min_length: int | None = None,
+        max_length: int | None = None,
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
+
+class SetType(BaseType):
+    item_type: BaseType
+    frozen: bool
+    forbid_duplicates: bool
+    sort_on_dump: bool
+
+    def __init__(
+        self,
+        item_type: BaseType,
+        frozen: bool = False,
+        forbid_duplicates: bool = False,
+        sort_on_dump: bool = False,
+        custom_encoder: CustomEncoder[Any, Any] | None = None,
+    ): ...
+
 class BytesType(BaseType):
     def __init__(self, custom_encoder: CustomEncoder[Any, Any] | None = None): ...
 
@@ -201,24 +328,30 @@ class AnyType(BaseType):
 class UnionType(BaseType):
     item_types: list[BaseType]
     union_repr: str
+    smart: bool
 
     def __init__(
         self,
         item_types: list[BaseType],
         union_repr: str,
+        smart: bool = False,
         custom_encoder: CustomEncoder[Any, Any] | None = None,
     ): ...
 
 class DiscriminatedUnionType(BaseType):
-    item_types: dict[str, BaseType]
-    dump_discriminator: str
-    load_discriminator: str
+    item_types: dict[str | int, BaseType]
+    dump_discriminator: str | Callable[[Any], str | int | None]
+    load_discriminator: str | Callable[[Any], str | int | None]
+    default_tag: str | int | None
+    fallback: BaseType | None
 
     def __init__(
         self,
-        item_types: dict[str, BaseType],
-        dump_discriminator: str,
-        load_discriminator: str,
+        item_types: dict[str | int, BaseType],
+        dump_discriminator: str | Callable[[Any], str | int | None],
+        load_discriminator: str | Callable[[Any], str | int | None],
+        default_tag: str | int | None = None,
+        fallback: BaseType | None = None,
         custom_encoder: CustomEncoder[Any, Any] | None = None,
     ): ...
 
@@ -1,5 +1,7 @@
+import json
 import sys
 import typing
+from decimal import Decimal
 from enum import Enum
 from functools import singledispatch
 from typing import Any, Optional, Union, cast
@@ -71,7 +73,14 @@ def to_json_schema(_: Any, doc: Optional[str] = None, *, config: Config) -> Sche
 
 @to_json_schema.register
 def _(arg: describe.StringType, doc: Optional[str] = None, *, config: Config) -> Schema:
-    return StringType(minLength=arg.min_length, maxLength=arg.max_length, description=doc, config=config)
+    return StringType(
+        minLength=arg.min_length,
+        maxLength=arg.max_length,
+        pattern=arg.pattern,
+        format=arg.format,
+        description=doc,
+        config=config,
+    )
 
 
 @to_json_schema.register
@@ -90,12 +99,16 @@ def _(arg: describe.FloatType, doc: Optional[str] = None, *, config: Config) ->
 
 
 @to_json_schema.register
-def _(_: describe.DecimalType, doc: Optional[str] = None, *, config: Config) -> Schema:
-    # todo: support min/max
+def _(arg: describe.DecimalType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    # JSON schema bounds are numbers, `Decimal` ones are written as floats
+    minimum = float(arg.min) if isinstance(arg.min, Decimal) else arg.min
+    maximum = float(arg.max) if isinstance(arg.max, Decimal) else arg.max
+    if arg.dump_as_number:
+        return NumberType(format='decimal', minimum=minimum, maximum=maximum, description=doc, config=config)
     return Schema(
         oneOf=[
             StringType(format='decimal', config=config),
-            NumberType(format='decimal', config=config),
+            NumberType(format='decimal', minimum=minimum, maximum=maximum, config=config),
         ],
         description=doc,
         config=config,
@@ -113,17 +126,40 @@ def _(_: describe.UUIDType, doc: Optional[str] = None, *, config: Config) -> Sch
 
 
 @to_json_schema.register
-def _(_: describe.TimeType, doc: Optional[str] = None, *, config: Config) -> Schema:
+def _(arg: describe.TimeType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    if arg.format is not None:
+        return StringType(description=doc, config=config)
     return StringType(format='time', description=doc, config=config)
 
 
 @to_json_schema.register
-def _(_: describe.DateTimeType, doc: Optional[str] = None, *, config: Config) -> Schema:
+def _(arg: describe.DateTimeType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    if arg.timestamp_unit is not None:
+        if arg.timestamp_as_float:
+            return NumberType(description=doc, config=config)
+        return IntegerType(description=doc, config=config)
+    if arg.format is not None:
+        return StringType(description=doc, config=config)
     return StringType(format='date-time', description=doc, config=config)
 
 
 @to_json_schema.register
-def _(_: describe.DateType, doc: Optional[str] = None, *, config: Config) -> Schema:
+def _(arg: describe.TimeDeltaType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    if arg.format == 'seconds':
+        return NumberType(description=doc, config=config)
+    if arg.format == 'int_seconds':
+        return IntegerType(description=doc, config=config)
+    return StringType(format='duration', description=doc, config=config)
+
+
+@to_json_schema.register
+def _(arg: describe.DateType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    if arg.timestamp_unit is not None:
+        if arg.timestamp_as_float:
+            return NumberType(description=doc, config=config)
+        return IntegerType(description=doc, config=config)
+    if arg.format is not None:
+        return StringType(description=doc, config=config)
     return StringType(format='date', description=doc, config=config)
 
 
@@ -144,6 +180,16 @@ def _(arg: describe.EnumType, doc: Optional[str] = None, *, config: Config) -> S
     )
 
 
+@to_json_schema.register
+def _(arg: describe.ValidatedType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    return to_json_schema(arg.inner, doc, config=config)
+
+
+@to_json_schema.register
+def _(arg: describe.CoercionType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    return to_json_schema(arg.inner, doc, config=config)
+
+
 @to_json_schema.register
 def _(arg: describe.OptionalType, doc: Optional[str] = None, *, config: Config) -> Schema:
     return Schema(
@@ -158,9 +204,11 @@ def _(arg: describe.OptionalType, doc: Optional[str] = None, *, config: Config)
 
 @to_json_schema.register
 def _(arg: describe.EntityType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    fields = [prop for prop in arg.fields if not prop.is_extra_fields]
     return ObjectType(
-        properties={prop.dict_key: to_json_schema(prop.field_type, prop.doc, config=config) for prop in arg.fields},
-        required=[prop.dict_key for prop in arg.fields if prop.required] or None,
+        properties={prop.dict_key: _get_field_schema(prop, config) for prop in fields},
+        required=[prop.dict_key for prop in fields if prop.required] or None,
+        additionalProperties=_get_additional_properties(arg, config),
         name=arg.name,
         description=arg.doc,
         config=config,
@@ -169,15 +217,36 @@ def _(arg: describe.EntityType, doc: Optional[str] = None, *, config: Config) ->
 
 @to_json_schema.register
 def _(arg: describe.TypedDictType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    fields = [prop for prop in arg.fields if not prop.is_extra_fields]
     return ObjectType(
-        properties={prop.dict_key: to_json_schema(prop.field_type, prop.doc, config=config) for prop in arg.fields},
-        required=[prop.dict_key for prop in arg.fields if prop.required] or None,
+        properties={prop.dict_key: _get_field_schema(prop, config) for prop in fields},
+        required=[prop.dict_key for prop in fields if prop.required] or None,
+        additionalProperties=_get_additional_properties(arg, config),
         name=arg.name,
         description=arg.doc,
         config=config,
     )
 
 
+def _get_field_schema(prop: describe.EntityField, config: Config) -> Schema:
+    schema = to_json_schema(prop.field_type, prop.doc, config=config)
+    schema.readOnly = prop.read_only or None
+    schema.writeOnly = prop.write_only or None
+    return schema
+
+
+def _get_additional_properties(
+    arg: Union[describe.EntityType, describe.TypedDictType], config: Config
+) -> Union[bool, Schema, None]:
+    for prop in arg.fields:
+        if prop.is_extra_fields:
+            field_type = prop.field_type
+            while isinstance(field_type, (describe.ValidatedType, describe.CoercionType)):
+                field_type = field_type.inner
+            return to_json_schema(cast(describe.DictionaryType, field_type).value_type, config=config)
+    return False if arg.forbid_extra else None
+
+
 @to_json_schema.register
 def _(arg: describe.ArrayType, doc: Optional[str] = None, *, config: Config) -> Schema:
     return ArrayType(
@@ -207,6 +276,27 @@ def _(arg: describe.TupleType, doc: Optional[str] = None, *, config: Config) ->
     )
 
 
+@to_json_schema.register
+def _(arg: describe.VarTupleType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    return ArrayType(
+        items=to_json_schema(arg.item_type, config=config),
+        minItems=arg.min_length,
+        maxItems=arg.max_length,
+        description=doc,
+        config=config,
+    )
+
+
+@to_json_schema.register
+def _(arg: describe.SetType, doc: Optional[str] = None, *, config: Config) -> Schema:
+    return ArrayType(
+        items=to_json_schema(arg.item_type, config=config),
+        uniqueItems=True,
+        description=doc,
+        config=config,
+    )
+
+
 @to_json_schema.register
 def _(_: describe.AnyType, doc: Optional[str] = None, *, config: Config) -> Schema:
     return Schema(description=doc, config=config)
@@ -237,18 +327,30 @@ def _(arg: describe.UnionType, doc: Optional[str] = None, *, config: Config) ->
 
 @to_json_schema.register
 def _(arg: describe.DiscriminatedUnionType, doc: Optional[str] = None, *, config: Config) -> Schema:
-    objects = {
-        name: schema
-        for name, t in arg.item_types.items()
-        if (schema := to_json_schema(t, config=config)) and _check_unions_schema_types(schema)
-    }
+    # OpenAPI discriminator supports only top level properties
+    by_property = isinstance(arg.load_discriminator, str) and '.' not in arg.load_discriminator
+    one_of: list[Schema] = []
+    objects: dict[str, Union[ObjectType, RefType]] = {}
+    for name, t in arg.item_types.items():
+        schema = to_json_schema(t, config=config)
+        one_of.append(schema)
+        # Custom types have arbitrary schemas, so they are not in the discriminator mapping
+        if by_property and not isinstance(t, describe.CustomType) and _check_unions_schema_types(schema):
+            # Mapping keys are strings, other tags are written as JSON values
+            objects[name if isinstance(name, str) else json.dumps(name)] = schema
+
+    if arg.fallback is not None:
+        # Values with unknown tags, they are not in the discriminator mapping
+        one_of.append(to_json_schema(arg.fallback, config=config))
 
     return DiscriminatedUnionType(
-        oneOf=list(objects.values()),
+        oneOf=one_of,
         discriminator=Discriminator(
             property_name=arg.load_discriminator,
             mapping={name: val.ref for name, val in objects.items()},
-        ),
+        )
+        if isinstance(arg.load_discriminator, str) and by_property
+        else None,
         description=doc,
         config=config,
     )
@@ -20,6 +20,8 @@ class Schema:
     description: str | None = None
     default: Any | None = None
     enum: list[Any] | None = None
+    readOnly: bool | None = None
+    writeOnly: bool | None = None
 
     allOf: list[Schema] | None = None
     anyOf: list[Schema] | None = None
@@ -33,6 +35,8 @@ class Schema:
             'description': self.description,
             'default': self.default,
             'enum': self.enum,
+            'readOnly': self.readOnly,
+            'writeOnly': self.writeOnly,
             'allOf': [item.dump(definitions) for item in self.allOf] if self.allOf else None,
             'anyOf': [item.dump(definitions) for item in self.anyOf] if self.anyOf else None,
             'oneOf': [item.dump(definitions) for item in self.oneOf] if self.oneOf else None,
@@ -56,6 +60,7 @@ class StringType(Schema):
     type: str = 'string'  # pyright: ignore[reportIncompatibleVariableOverride]
     minLength: int | None = None
     maxLength: int | None = None
+    pattern: str | None = None
     format: str | None = None
 
     def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
@@ -63,6 +68,7 @@ class StringType(Schema):
         data = {
             'minLength': self.minLength,
             'maxLength': self.maxLength,
+            'pattern': self.pattern,
             'format': self.format,
             **data,
         }
@@ -119,9 +125,12 @@ class ObjectType(Schema):
         data = {k: v for k, v in data.items() if v is not None}
         if not self.name:
             return data
+        # Annotations of the field are kept next to the reference, the definition is shared
+        access = {key: data.pop(key) for key in ('readOnly', 'writeOnly') if key in data}
         definitions[self.name] = data
         return {
             '$ref': self.ref,
+            **access,
         }
 
 
@@ -132,6 +141,7 @@ class ArrayType(Schema):
     prefixItems: list[Schema] | None = None
     minItems: int | None = None
     maxItems: int | None = None
+    uniqueItems: bool | None = None
 
     def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
         data = super().dump(definitions)
@@ -140,6 +150,7 @@ class ArrayType(Schema):
             'prefixItems': [i.dump(definitions) for i in self.prefixItems] if self.prefixItems else None,
             'minItems': self.minItems,
             'maxItems': self.maxItems,
+            'uniqueItems': self.uniqueItems,
             **data,
         }
         return {k: v for k, v in data.items() if v is not None}
@@ -167,8 +178,10 @@ class DiscriminatedUnionType(Schema):
 
     def dump(self, definitions: dict[str, Any]) -> dict[str, Any]:
         data = super().dump(definitions)
+        if self.discriminator is None:
+            return data
         return {
-            'discriminator': self.discriminator.dump() if self.discriminator else None,
+            'discriminator': self.discriminator.dump(),
             **data,
         }
 
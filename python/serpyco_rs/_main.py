@@ -6,7 +6,16 @@ from ._custom_types import CustomType
 from ._describe import BaseType, describe_type
 from ._impl import Serializer as _Serializer
 from ._json_schema import get_json_schema
-from .metadata import CamelCase, ForceDefaultForOptional, OmitNone
+from .metadata import (
+    CamelCase,
+    Coercion,
+    CoercionMode,
+    EntityInit,
+    ForbidExtra,
+    ForceDefaultForOptional,
+    InitMode,
+    OmitNone,
+)
 
 
 _T = TypeVar('_T', bound=Any)
@@ -39,7 +48,11 @@ class Serializer(Generic[_T]):
         camelcase_fields: bool = False,
         omit_none: bool = False,
         force_default_for_optional: bool = False,
+        forbid_extra: bool = False,
+        init_mode: InitMode = InitMode.skip,
+        coercion_mode: CoercionMode = CoercionMode.default,
         naive_datetime_to_utc: bool = False,
+        collect_errors: bool = False,
         custom_type_resolver: Optional[Callable[[Any], Optional[CustomType[Any, Any]]]] = None,
     ) -> None:
         """
@@ -49,7 +62,15 @@ class Serializer(Generic[_T]):
         :param camelcase_fields: If True, the serializer will convert field names to camelCase.
         :param omit_none: If True, the serializer will omit None values from the output.
         :param force_default_for_optional: If True, the serializer will force default values for optional fields.
+        :param forbid_extra: If True, the serializer will reject unknown keys of objects on load.
+        :param init_mode: How loaded objects are initialized: fields are set without calling any code (default),
+            `__post_init__` is called after the fields are set, or the objects are built through the class constructor.
+        :param coercion_mode: Which values of other types are accepted on load: `strict` accepts only values
+            of the expected type, `lax` also parses numeric and boolean strings, integral floats for ints
+            and 0 / 1 for bools. Can be overridden for fields with the `Strict` / `Lax` / `DefaultCoercion` annotations.
         :param naive_datetime_to_utc: If True, the serializer will convert naive datetimes to UTC.
+        :param collect_errors: If True, loading doesn't stop on the first validation error
+            and raises SchemaValidationError with all errors found.
         :param custom_type_resolver: An optional callable that allows users to add support for their own types.
             This parameter should be a function that takes a type as input and returns an instance of CustomType
             if the user-defined type is supported, or None otherwise.
@@ -60,9 +81,15 @@ class Serializer(Generic[_T]):
             t = cast(type(_T), Annotated[t, OmitNone])  # type: ignore
         if force_default_for_optional:
             t = cast(type(_T), Annotated[t, ForceDefaultForOptional])  # type: ignore
+        if forbid_extra:
+            t = cast(type(_T), Annotated[t, ForbidExtra])  # type: ignore
+        if init_mode is not InitMode.skip:
+            t = cast(type(_T), Annotated[t, EntityInit(init_mode)])  # type: ignore
+        if coercion_mode is not CoercionMode.default:
+            t = cast(type(_T), Annotated[t, Coercion(coercion_mode)])  # type: ignore
         self._type_info = describe_type(t, custom_type_resolver=custom_type_resolver)
         self._schema = get_json_schema(self._type_info)
-        self._encoder: _Serializer[_T] = _Serializer(self._type_info, naive_datetime_to_utc)
+        self._encoder: _Serializer[_T] = _Serializer(self._type_info, naive_datetime_to_utc, collect_errors)
 
     def dump(self, value: _T) -> Any:
         """Serialize the given value to a JSON-serializable object.
@@ -71,6 +98,13 @@ class Serializer(Generic[_T]):
         """
         return self._encoder.dump(value)
 
+    def dump_json(self, value: _T) -> bytes:
+        """Serialize the given value to JSON (UTF-8 encoded bytes).
+
+        :param value: The value to serialize.
+        """
+        return self._encoder.dump_json(value)
+
     def load(self, data: Any) -> _T:
         """Deserialize the given JSON-serializable object to the target type.
 
@@ -78,6 +112,13 @@ class Serializer(Generic[_T]):
         """
         return self._encoder.load(data)
 
+    def load_json(self, data: Union[bytes, str]) -> _T:
+        """Deserialize the given JSON document to the target type.
+
+        :param data: The JSON document (bytes or str) to deserialize.
+        """
+        return self._encoder.load_json(data)
+
     def load_query_params(self, data: _MultiMapping[Any, Any]) -> _T:
         """Deserialize the given query parameters to the target type.
 
@@ -2,7 +2,7 @@ from dataclasses import dataclass
 from typing import Any, Optional
 
 from serpyco_rs._impl import BaseType
-from serpyco_rs.metadata import FieldFormat, NoneAsDefaultForOptional, NoneFormat
+from serpyco_rs.metadata import EntityInit, ExtraKeys, FieldFormat, NoneAsDefaultForOptional, NoneFormat
 
 
 @dataclass(frozen=True, unsafe_hash=True)
@@ -11,6 +11,8 @@ class MetaStateKey:
     field_format: FieldFormat
     none_format: NoneFormat
     none_as_default_for_optional: NoneAsDefaultForOptional
+    extra_keys: ExtraKeys
+    entity_init: EntityInit
 
 
 @dataclass
@@ -1,19 +1,61 @@
 from collections.abc import Callable
-from dataclasses import dataclass
+from dataclasses import dataclass, field
+from decimal import Decimal
 from enum import Enum
-from typing import TypeVar, Union
+from typing import Any, Literal, Optional, TypeVar, Union
 
 from ._impl import CustomEncoder
 
 
+_C = TypeVar('_C', bound=type)
+
+
 @dataclass(frozen=True)
 class Min:
-    value: Union[int, float]
+    value: Union[int, float, Decimal]
 
 
 @dataclass(frozen=True)
 class Max:
-    value: Union[int, float]
+    value: Union[int, float, Decimal]
+
+
+@dataclass(frozen=True)
+class MaxDigits:
+    """Maximum number of significant digits of `Decimal` values, trailing zeros of the fractional part are ignored."""
+
+    value: int
+
+
+@dataclass(frozen=True)
+class DecimalPlaces:
+    """
+    Maximum number of decimal places of `Decimal` values.
+    With `rounding` (one of `decimal.ROUND_*` modes) loaded values are rounded instead of being rejected.
+    """
+
+    value: int
+    rounding: Optional[
+        Literal[
+            'ROUND_CEILING',
+            'ROUND_DOWN',
+            'ROUND_FLOOR',
+            'ROUND_HALF_DOWN',
+            'ROUND_HALF_EVEN',
+            'ROUND_HALF_UP',
+            'ROUND_UP',
+            'ROUND_05UP',
+        ]
+    ] = None
+
+
+@dataclass(frozen=True)
+class DecimalFormat:
+    as_number: bool
+
+
+DecimalAsString: DecimalFormat = DecimalFormat(False)
+DecimalAsNumber: DecimalFormat = DecimalFormat(True)
 
 
 @dataclass(frozen=True)
@@ -26,9 +68,87 @@ class MaxLength:
     value: int
 
 
+@dataclass(frozen=True)
+class Pattern:
+    """Regular expression the loaded string must match (searched anywhere in the string, like JSON Schema)."""
+
+    value: str
+
+
+@dataclass(frozen=True)
+class StringFormat:
+    """Semantic string format checked on load and emitted as JSON Schema `format`."""
+
+    value: Literal['email', 'uri', 'hostname', 'ipv4', 'ipv6', 'uuid', 'date', 'time', 'date-time']
+
+
+@dataclass(frozen=True)
+class DuplicateItems:
+    forbid: bool
+
+
+MergeDuplicates: DuplicateItems = DuplicateItems(False)
+ForbidDuplicates: DuplicateItems = DuplicateItems(True)
+
+
+@dataclass(frozen=True)
+class ItemsOrder:
+    sort: bool
+
+
+UnsortedItems: ItemsOrder = ItemsOrder(False)
+SortedItems: ItemsOrder = ItemsOrder(True)
+
+
 @dataclass(frozen=True)
 class Discriminator:
-    name: str
+    """
+    Tag field of the union variants.
+
+    `name` can be a dotted path to a nested field or a function returning the tag of the loaded data,
+    `dump` is a function returning the tag of the dumped value, by default it's taken the same way as on load.
+    `default_tag` is used for values without the tag field,
+    values with unknown tags are loaded and dumped as the `fallback` type.
+    """
+
+    name: Union[str, Callable[[Any], Union[str, int, None]]]
+    default_tag: Union[str, int, Enum, None] = None
+    fallback: Any = None
+    dump: Optional[Callable[[Any], Union[str, int, None]]] = None
+
+
+@dataclass(frozen=True)
+class Tag:
+    """Tag of the union variant, required for variants of unions with callable discriminator."""
+
+    value: Union[str, int, Enum]
+
+
+@dataclass(frozen=True)
+class UnionMode:
+    """In smart mode union variants of the exact value type are preferred over the declaration order."""
+
+    smart: bool
+
+
+LeftToRight: UnionMode = UnionMode(False)
+SmartUnion: UnionMode = UnionMode(True)
+
+
+@dataclass(frozen=True)
+class Validator:
+    """
+    Validates the loaded value, `ValueError`s raised by `func` are reported as validation errors.
+    Used as a decorator of a dataclass or attrs class it validates every loaded object of the class,
+    e.g. for cross-field checks.
+    """
+
+    func: Callable[[Any], None]
+
+    def __call__(self, cls: _C) -> _C:
+        validators = (*getattr(cls, '__serpyco_validators__', ()), self.func)
+        cls.__serpyco_validators__ = validators  # type: ignore[attr-defined]
+        return cls
 
 
 @dataclass(frozen=True)
@@ -50,6 +170,65 @@ CamelCase: FieldFormat = FieldFormat(Format.camel_case)
 NoFormat: FieldFormat = FieldFormat(Format.no_format)
 
 
+class DurationFormat(Enum):
+    iso = 'iso'
+    seconds = 'seconds'
+    int_seconds = 'int_seconds'
+
+
+@dataclass(frozen=True)
+class TimeDeltaFormat:
+    format: DurationFormat
+
+
+IsoDuration: TimeDeltaFormat = TimeDeltaFormat(DurationFormat.iso)
+TotalSeconds: TimeDeltaFormat = TimeDeltaFormat(DurationFormat.seconds)
+TotalSecondsInt: TimeDeltaFormat = TimeDeltaFormat(DurationFormat.int_seconds)
+
+
+@dataclass(frozen=True)
+class DateFormat:
+    """strftime-like format used to load / dump dates, times and datetimes instead of ISO 8601."""
+
+    value: str
+
+
+@dataclass(frozen=True)
+class Timestamp:
+    """Loads / dumps datetimes as Unix timestamps, loaded values are aware UTC datetimes."""
+
+    unit: Literal['s', 'ms', 'us'] = 's'
+    as_float: bool = False
+
+
+class TzPolicy(Enum):
+    require_aware = 'require_aware'
+    require_naive = 'require_naive'
+    convert_to_utc = 'convert_to_utc'
+    assume_timezone = 'assume_timezone'
+
+
+@dataclass(frozen=True)
+class TimezonePolicy:
+    """Checks or adjusts the timezone of datetimes on load and dump."""
+
+    policy: TzPolicy
+    timezone: Optional[str] = None
+
+
+@dataclass(frozen=True)
+class AssumeTimezone(TimezonePolicy):
+    """Naive datetimes are considered to be in the given IANA timezone, e.g. `AssumeTimezone('Europe/Berlin')`."""
+
+    policy: TzPolicy = field(default=TzPolicy.assume_timezone, init=False)
+    timezone: Optional[str] = None
+
+
+RequireAware: TimezonePolicy = TimezonePolicy(TzPolicy.require_aware)
+RequireNaive: TimezonePolicy = TimezonePolicy(TzPolicy.require_naive)
+ConvertToUTC: TimezonePolicy = TimezonePolicy(TzPolicy.convert_to_utc)
+
+
 @dataclass(frozen=True)
 class NoneFormat:
     omit: bool
@@ -59,6 +238,72 @@ KeepNone: NoneFormat = NoneFormat(False)
 OmitNone: NoneFormat = NoneFormat(True)
 
 
+@dataclass(frozen=True)
+class ExtraKeys:
+    forbid: bool
+
+
+IgnoreExtra: ExtraKeys = ExtraKeys(False)
+ForbidExtra: ExtraKeys = ExtraKeys(True)
+
+
+@dataclass(frozen=True)
+class FieldAccess:
+    """
+    `ReadOnly` fields are only dumped, on load their keys are ignored (or rejected with `ForbidExtra`)
+    and dataclass fields get the default value. `WriteOnly` fields are only loaded.
+    """
+
+    load: bool
+    dump: bool
+
+
+ReadOnly: FieldAccess = FieldAccess(load=False, dump=True)
+WriteOnly: FieldAccess = FieldAccess(load=True, dump=False)
+
+
+@dataclass(frozen=True)
+class ExtraFieldsMarker:
+    pass
+
+
+ExtraFields: ExtraFieldsMarker = ExtraFieldsMarker()
+
+
+class InitMode(Enum):
+    skip = 'skip'
+    post_init = 'post_init'
+    constructor = 'constructor'
+
+
+@dataclass(frozen=True)
+class EntityInit:
+    mode: InitMode
+
+
+SkipInit: EntityInit = EntityInit(InitMode.skip)
+CallPostInit: EntityInit = EntityInit(InitMode.post_init)
+CallConstructor: EntityInit = EntityInit(InitMode.constructor)
+
+
+class CoercionMode(Enum):
+    default = 'default'
+    strict = 'strict'
+    lax = 'lax'
+
+
+@dataclass(frozen=True)
+class Coercion:
+    """Which values of other types are accepted on load, applies to all nested values."""
+
+    mode: CoercionMode
+
+
+DefaultCoercion: Coercion = Coercion(CoercionMode.default)
+Strict: Coercion = Coercion(CoercionMode.strict)
+Lax: Coercion = Coercion(CoercionMode.lax)
+
+
 @dataclass(frozen=True)
 class NoneAsDefaultForOptional:
     use: bool
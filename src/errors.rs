@@ -78,23 +78,33 @@ impl SchemaValidationError {
 }
 
 #[pyclass(module = "serpyco_rs")]
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug)]
 pub(crate) struct ErrorItem {
     #[pyo3(get, set)]
     message: String,
     #[pyo3(get)]
-    instance_path: String,
+    pub(crate) instance_path: String,
+    /// Number of the `instance_path` chunks, path keys may contain `/` themselves.
+    pub(crate) depth: usize,
 }
 
-#[pymethods]
 impl ErrorItem {
-    #[new]
-    pub fn new(message: String, instance_path: String) -> Self {
+    pub(crate) fn with_depth(message: String, instance_path: String, depth: usize) -> Self {
         ErrorItem {
             message,
             instance_path,
+            depth,
         }
     }
+}
+
+#[pymethods]
+impl ErrorItem {
+    /// Items created in Python are assumed to be at the root.
+    #[new]
+    pub fn new(message: String, instance_path: String) -> Self {
+        Self::with_depth(message, instance_path, 0)
+    }
 
     fn __str__(&self) -> String {
         format!("{} (instance_path='{}')", self.message, self.instance_path)
@@ -106,7 +116,9 @@ impl ErrorItem {
         )
     }
     fn __richcmp__(&self, other: &ErrorItem, op: CompareOp) -> bool {
-        op.matches(self.cmp(other))
+        op.matches(
+            (&self.message, &self.instance_path).cmp(&(&other.message, &other.instance_path)),
+        )
     }
 }
 
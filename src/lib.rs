@@ -22,6 +22,7 @@ fn _serpyco_rs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<types::TimeType>()?;
     m.add_class::<types::DateTimeType>()?;
     m.add_class::<types::DateType>()?;
+    m.add_class::<types::TimeDeltaType>()?;
     m.add_class::<types::EntityType>()?;
     m.add_class::<types::TypedDictType>()?;
     m.add_class::<types::EntityField>()?;
@@ -29,8 +30,12 @@ fn _serpyco_rs(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<types::ArrayType>()?;
     m.add_class::<types::EnumType>()?;
     m.add_class::<types::OptionalType>()?;
+    m.add_class::<types::ValidatedType>()?;
+    m.add_class::<types::CoercionType>()?;
     m.add_class::<types::DictionaryType>()?;
     m.add_class::<types::TupleType>()?;
+    m.add_class::<types::VarTupleType>()?;
+    m.add_class::<types::SetType>()?;
     m.add_class::<types::BytesType>()?;
     m.add_class::<types::AnyType>()?;
     m.add_class::<types::UnionType>()?;
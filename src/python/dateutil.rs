@@ -1,12 +1,18 @@
+use std::fmt;
+use std::fmt::Write;
+
+use chrono::format::{self, Fixed, Item, Parsed, StrftimeItems};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::PyAnyMethods;
 use pyo3::types::{
-    PyDate, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyTime, PyTimeAccess, PyTzInfo,
-    PyTzInfoAccess,
+    timezone_utc_bound, PyDate, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyDict,
+    PyDictMethods, PyTime, PyTimeAccess, PyTzInfo, PyTzInfoAccess,
 };
-use pyo3::{Bound, PyErr, PyResult, Python};
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult, Python};
 use pyo3_ffi::PyTimeZone_FromOffset;
 use speedate::{
-    Date, DateTime, MicrosecondsPrecisionOverflowBehavior, ParseError, Time, TimeConfig,
+    Date, DateTime, Duration, MicrosecondsPrecisionOverflowBehavior, ParseError, Time, TimeConfig,
 };
 
 use crate::errors::{ToPyErr, ValidationError};
@@ -57,20 +63,112 @@ pub(crate) fn parse_date<'py>(py: Python<'py>, value: &str) -> PyResult<Bound<'p
 }
 
 #[inline]
-fn time_as_tzinfo<'py>(py: Python<'py>, time: &Time) -> PyResult<Option<Bound<'py, PyTzInfo>>> {
-    match time.tz_offset {
-        Some(offset) => {
-            let delta = PyDelta::new_bound(py, 0, offset, 0, true)?;
+pub(crate) fn parse_timedelta<'py>(py: Python<'py>, value: &str) -> PyResult<Bound<'py, PyDelta>> {
+    let duration =
+        Duration::parse_bytes_with_config(value.as_ref(), &TIME_CONFIG).map_err(|err| {
+            ValidationError::new_err(format!("{:?} is not a valid duration: {}", value, err))
+        })?;
+    // Years and months have no fixed length, speedate approximates them as 365 and 30 days
+    let date_part = value.split('T').next().unwrap_or_default();
+    if date_part.contains(['Y', 'M']) {
+        return Err(ValidationError::new_err(format!(
+            "{:?} is not a valid duration: years and months are not supported",
+            value
+        )));
+    }
+    let sign = if duration.positive { 1 } else { -1 };
+    let microseconds = sign
+        * (i128::from(duration.day) * MICROSECONDS_PER_DAY
+            + i128::from(duration.second) * MICROSECONDS_PER_SECOND
+            + i128::from(duration.microsecond));
+    timedelta_from_microseconds(py, microseconds)?.ok_or_else(|| {
+        ValidationError::new_err(format!("{:?} is out of the duration range", value))
+    })
+}
+
+const MICROSECONDS_PER_SECOND: i128 = 1_000_000;
+const MICROSECONDS_PER_DAY: i128 = 86_400 * MICROSECONDS_PER_SECOND;
+// `timedelta.min.days` / `timedelta.max.days`
+const MAX_TIMEDELTA_DAYS: i128 = 999_999_999;
 
-            let tzinfo =
-                unsafe { Bound::from_owned_ptr(py, PyTimeZone_FromOffset(delta.as_ptr())) };
+/// Creates a `timedelta`, returns `None` if the value is out of the `timedelta` range.
+#[inline]
+pub(crate) fn timedelta_from_microseconds(
+    py: Python<'_>,
+    microseconds: i128,
+) -> PyResult<Option<Bound<'_, PyDelta>>> {
+    let days = microseconds.div_euclid(MICROSECONDS_PER_DAY);
+    if !(-MAX_TIMEDELTA_DAYS..=MAX_TIMEDELTA_DAYS).contains(&days) {
+        return Ok(None);
+    }
+    let rest = microseconds.rem_euclid(MICROSECONDS_PER_DAY);
+    PyDelta::new_bound(
+        py,
+        days as i32,
+        (rest / MICROSECONDS_PER_SECOND) as i32,
+        (rest % MICROSECONDS_PER_SECOND) as i32,
+        false,
+    )
+    .map(Some)
+}
 
-            Ok(Some(tzinfo.downcast_into()?))
+#[inline]
+pub(crate) fn timedelta_to_microseconds(value: &Bound<'_, PyDelta>) -> i128 {
+    i128::from(value.get_days()) * MICROSECONDS_PER_DAY
+        + i128::from(value.get_seconds()) * MICROSECONDS_PER_SECOND
+        + i128::from(value.get_microseconds())
+}
+
+/// Formats the `timedelta` as an ISO 8601 duration using days as the largest unit (`P1DT2H30M`).
+pub(crate) fn dump_timedelta(value: &Bound<'_, PyDelta>) -> String {
+    let microseconds = timedelta_to_microseconds(value);
+    let mut result = String::from(if microseconds < 0 { "-P" } else { "P" });
+    let microseconds = microseconds.unsigned_abs();
+    let days = microseconds / MICROSECONDS_PER_DAY as u128;
+    let seconds = (microseconds % MICROSECONDS_PER_DAY as u128) / MICROSECONDS_PER_SECOND as u128;
+    let fraction = microseconds % MICROSECONDS_PER_SECOND as u128;
+    if days != 0 {
+        result.push_str(&format!("{}D", days));
+    }
+    if seconds != 0 || fraction != 0 || days == 0 {
+        result.push('T');
+        let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+        if hours != 0 {
+            result.push_str(&format!("{}H", hours));
+        }
+        if minutes != 0 {
+            result.push_str(&format!("{}M", minutes));
+        }
+        if seconds != 0 || fraction != 0 || (hours == 0 && minutes == 0) {
+            result.push_str(&seconds.to_string());
+            if fraction != 0 {
+                let fraction = format!("{:06}", fraction);
+                result.push('.');
+                result.push_str(fraction.trim_end_matches('0'));
+            }
+            result.push('S');
         }
+    }
+    result
+}
+
+#[inline]
+fn time_as_tzinfo<'py>(py: Python<'py>, time: &Time) -> PyResult<Option<Bound<'py, PyTzInfo>>> {
+    match time.tz_offset {
+        Some(offset) => Ok(Some(tzinfo_from_offset(py, offset)?)),
         None => Ok(None),
     }
 }
 
+#[inline]
+fn tzinfo_from_offset(py: Python<'_>, offset: i32) -> PyResult<Bound<'_, PyTzInfo>> {
+    let delta = PyDelta::new_bound(py, 0, offset, 0, true)?;
+
+    let tzinfo = unsafe { Bound::from_owned_ptr(py, PyTimeZone_FromOffset(delta.as_ptr())) };
+
+    Ok(tzinfo.downcast_into()?)
+}
+
 struct InnerParseError(ParseError);
 
 impl From<ParseError> for InnerParseError {
@@ -118,6 +216,107 @@ pub(crate) fn dump_date(value: &Bound<PyDate>) -> PyResult<String> {
     Ok(date.to_string())
 }
 
+/// Creates an aware UTC `datetime` from microseconds since the Unix epoch,
+/// returns `None` if the value is out of the `datetime` range.
+pub(crate) fn datetime_from_timestamp(
+    py: Python<'_>,
+    microseconds: i128,
+) -> PyResult<Option<Bound<'_, PyDateTime>>> {
+    let days = microseconds.div_euclid(MICROSECONDS_PER_DAY);
+    let rest = microseconds.rem_euclid(MICROSECONDS_PER_DAY);
+    let Ok(days) = i64::try_from(days) else {
+        return Ok(None);
+    };
+    let (year, month, day) = civil_from_days(days);
+    if !(1..=9999).contains(&year) {
+        return Ok(None);
+    }
+    let seconds = (rest / MICROSECONDS_PER_SECOND) as u32;
+    PyDateTime::new_bound(
+        py,
+        year as i32,
+        month,
+        day,
+        (seconds / 3600) as u8,
+        (seconds % 3600 / 60) as u8,
+        (seconds % 60) as u8,
+        (rest % MICROSECONDS_PER_SECOND) as u32,
+        Some(&timezone_utc_bound(py)),
+    )
+    .map(Some)
+}
+
+/// Microseconds since the Unix epoch, naive datetimes are treated as UTC.
+pub(crate) fn datetime_to_timestamp(value: &Bound<'_, PyDateTime>) -> PyResult<i128> {
+    let days = days_from_civil(
+        i64::from(value.get_year()),
+        value.get_month(),
+        value.get_day(),
+    );
+    let seconds = i64::from(value.get_hour()) * 3600
+        + i64::from(value.get_minute()) * 60
+        + i64::from(value.get_second())
+        - i64::from(to_tz_offset(value, Some(value))?.unwrap_or(0));
+    Ok(i128::from(days) * MICROSECONDS_PER_DAY
+        + i128::from(seconds) * MICROSECONDS_PER_SECOND
+        + i128::from(value.get_microsecond()))
+}
+
+/// Creates a `date` from microseconds since the Unix epoch, returns `None` if the value
+/// is not a UTC midnight or is out of the `date` range.
+pub(crate) fn date_from_timestamp(
+    py: Python<'_>,
+    microseconds: i128,
+) -> PyResult<Option<Bound<'_, PyDate>>> {
+    if microseconds.rem_euclid(MICROSECONDS_PER_DAY) != 0 {
+        return Ok(None);
+    }
+    let Ok(days) = i64::try_from(microseconds.div_euclid(MICROSECONDS_PER_DAY)) else {
+        return Ok(None);
+    };
+    let (year, month, day) = civil_from_days(days);
+    if !(1..=9999).contains(&year) {
+        return Ok(None);
+    }
+    PyDate::new_bound(py, year as i32, month, day).map(Some)
+}
+
+/// Microseconds since the Unix epoch for the UTC midnight of the date.
+pub(crate) fn date_to_timestamp(value: &Bound<'_, PyDate>) -> i128 {
+    let days = days_from_civil(
+        i64::from(value.get_year()),
+        value.get_month(),
+        value.get_day(),
+    );
+    i128::from(days) * MICROSECONDS_PER_DAY
+}
+
+/// Days since 1970-01-01 for the proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date for the number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 fn to_date(value: &dyn PyDateAccess) -> Date {
     Date {
         year: value.get_year() as u16,
@@ -136,6 +335,36 @@ fn to_time(value: &dyn PyTimeAccess) -> Time {
     }
 }
 
+/// Aware datetimes have a `tzinfo` returning an offset for them.
+pub(crate) fn is_aware(value: &Bound<'_, PyDateTime>) -> PyResult<bool> {
+    Ok(to_tz_offset(value, Some(value))?.is_some())
+}
+
+/// Converts aware datetimes to UTC, naive datetimes are treated as UTC.
+pub(crate) fn datetime_to_utc<'py>(
+    value: &Bound<'py, PyDateTime>,
+) -> PyResult<Bound<'py, PyDateTime>> {
+    let utc = timezone_utc_bound(value.py());
+    let result = if is_aware(value)? {
+        value.call_method1(intern!(value.py(), "astimezone"), (utc,))?
+    } else {
+        datetime_with_tzinfo(value, &utc)?.into_any()
+    };
+    Ok(result.downcast_into::<PyDateTime>()?)
+}
+
+/// Attaches `tzinfo` to the datetime keeping its wall time.
+pub(crate) fn datetime_with_tzinfo<'py>(
+    value: &Bound<'py, PyDateTime>,
+    tzinfo: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyDateTime>> {
+    let py = value.py();
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item(intern!(py, "tzinfo"), tzinfo)?;
+    let result = value.call_method(intern!(py, "replace"), (), Some(&kwargs))?;
+    Ok(result.downcast_into::<PyDateTime>()?)
+}
+
 fn to_tz_offset(
     value: &dyn PyTzInfoAccess,
     datetime: Option<&Bound<PyDateTime>>,
@@ -151,3 +380,180 @@ fn to_tz_offset(
     let offset = offset.downcast::<PyDelta>()?;
     Ok(Some(offset.get_days() * 86400 + offset.get_seconds()))
 }
+
+/// Custom strftime-like format (`%d.%m.%Y`) of dates, times and datetimes.
+#[derive(Debug, Clone)]
+pub(crate) struct DateFormat {
+    pattern: String,
+    items: Vec<Item<'static>>,
+    has_offset: bool,
+}
+
+impl DateFormat {
+    pub(crate) fn new(pattern: &str) -> Result<Self, String> {
+        let items = StrftimeItems::new(pattern)
+            .parse_to_owned()
+            .map_err(|_| format!("Invalid date format: {:?}", pattern))?;
+        let has_offset = items.iter().any(|item| {
+            matches!(
+                item,
+                Item::Fixed(
+                    Fixed::TimezoneOffset
+                        | Fixed::TimezoneOffsetColon
+                        | Fixed::TimezoneOffsetDoubleColon
+                        | Fixed::TimezoneOffsetTripleColon
+                        | Fixed::TimezoneOffsetColonZ
+                        | Fixed::TimezoneOffsetZ
+                )
+            )
+        });
+        Ok(Self {
+            pattern: pattern.to_string(),
+            items,
+            has_offset,
+        })
+    }
+
+    pub(crate) fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    #[inline]
+    fn parse(&self, value: &str) -> Option<Parsed> {
+        let mut parsed = Parsed::new();
+        format::parse(&mut parsed, value, self.items.iter()).ok()?;
+        Some(parsed)
+    }
+
+    pub(crate) fn parse_date<'py>(
+        &self,
+        py: Python<'py>,
+        value: &str,
+    ) -> PyResult<Option<Bound<'py, PyDate>>> {
+        match self.parse(value).and_then(|p| p.to_naive_date().ok()) {
+            Some(date) => Ok(Some(PyDate::new_bound(
+                py,
+                date.year(),
+                date.month() as u8,
+                date.day() as u8,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn parse_time<'py>(
+        &self,
+        py: Python<'py>,
+        value: &str,
+    ) -> PyResult<Option<Bound<'py, PyTime>>> {
+        match self.parse(value).and_then(|p| p.to_naive_time().ok()) {
+            Some(time) => Ok(Some(PyTime::new_bound(
+                py,
+                time.hour() as u8,
+                time.minute() as u8,
+                time.second() as u8,
+                microsecond(&time),
+                None,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn parse_datetime<'py>(
+        &self,
+        py: Python<'py>,
+        value: &str,
+    ) -> PyResult<Option<Bound<'py, PyDateTime>>> {
+        let Some(parsed) = self.parse(value) else {
+            return Ok(None);
+        };
+        let (Ok(date), Ok(time)) = (parsed.to_naive_date(), parsed.to_naive_time()) else {
+            return Ok(None);
+        };
+        let tzinfo = match parsed.offset {
+            Some(offset) => Some(tzinfo_from_offset(py, offset)?),
+            None => None,
+        };
+        Ok(Some(PyDateTime::new_bound(
+            py,
+            date.year(),
+            date.month() as u8,
+            date.day() as u8,
+            time.hour() as u8,
+            time.minute() as u8,
+            time.second() as u8,
+            microsecond(&time),
+            tzinfo.as_ref(),
+        )?))
+    }
+
+    pub(crate) fn dump_date(&self, value: &Bound<'_, PyDate>) -> PyResult<String> {
+        let date = to_naive_date(value)?;
+        self.format(date.format_with_items(self.items.iter()))
+    }
+
+    pub(crate) fn dump_time(&self, value: &Bound<'_, PyTime>) -> PyResult<String> {
+        let time = to_naive_time(value)?;
+        self.format(time.format_with_items(self.items.iter()))
+    }
+
+    pub(crate) fn dump_datetime(
+        &self,
+        value: &Bound<'_, PyDateTime>,
+        naive_datetime_to_utc: bool,
+    ) -> PyResult<String> {
+        let datetime = NaiveDateTime::new(to_naive_date(value)?, to_naive_time(value)?);
+        let offset = match to_tz_offset(value, Some(value))? {
+            Some(offset) => Some(offset),
+            None if naive_datetime_to_utc => Some(0),
+            None => None,
+        };
+        match offset.and_then(FixedOffset::east_opt) {
+            Some(offset) => {
+                let datetime = offset.from_local_datetime(&datetime).unwrap();
+                self.format(datetime.format_with_items(self.items.iter()))
+            }
+            None if self.has_offset => Err(PyValueError::new_err(format!(
+                "Date format {:?} requires an aware datetime",
+                self.pattern
+            ))),
+            None => self.format(datetime.format_with_items(self.items.iter())),
+        }
+    }
+
+    #[inline]
+    fn format(&self, value: impl fmt::Display) -> PyResult<String> {
+        let mut result = String::new();
+        write!(result, "{}", value).map_err(|_| {
+            PyValueError::new_err(format!("Failed to format value with {:?}", self.pattern))
+        })?;
+        Ok(result)
+    }
+}
+
+#[inline]
+fn microsecond(time: &NaiveTime) -> u32 {
+    // Leap seconds are represented by nanoseconds above 1_000_000_000
+    (time.nanosecond() / 1000).min(999_999)
+}
+
+#[inline]
+fn to_naive_date(value: &dyn PyDateAccess) -> PyResult<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        value.get_year(),
+        value.get_month().into(),
+        value.get_day().into(),
+    )
+    .ok_or_else(|| PyValueError::new_err("Invalid date"))
+}
+
+#[inline]
+fn to_naive_time(value: &dyn PyTimeAccess) -> PyResult<NaiveTime> {
+    NaiveTime::from_hms_micro_opt(
+        value.get_hour().into(),
+        value.get_minute().into(),
+        value.get_second().into(),
+        value.get_microsecond(),
+    )
+    .ok_or_else(|| PyValueError::new_err("Invalid time"))
+}
@@ -5,7 +5,10 @@ pub(super) mod types;
 mod utils;
 
 pub(crate) use dateutil::{
-    dump_date, dump_datetime, dump_time, parse_date, parse_datetime, parse_time,
+    date_from_timestamp, date_to_timestamp, datetime_from_timestamp, datetime_to_timestamp,
+    datetime_to_utc, datetime_with_tzinfo, dump_date, dump_datetime, dump_time, dump_timedelta,
+    is_aware, parse_date, parse_datetime, parse_time, parse_timedelta, timedelta_from_microseconds,
+    timedelta_to_microseconds, DateFormat,
 };
 pub(crate) use py::*;
 pub(crate) use types::{get_object_type, Type};
@@ -3,10 +3,10 @@ use pyo3::Bound;
 use pyo3::{PyAny, PyResult};
 
 use crate::validator::types::{
-    AnyType, ArrayType, BaseType, BooleanType, BytesType, CustomType, DateTimeType, DateType,
-    DecimalType, DictionaryType, DiscriminatedUnionType, EntityType, EnumType, FloatType,
-    IntegerType, LiteralType, OptionalType, RecursionHolder, StringType, TimeType, TupleType,
-    TypedDictType, UUIDType, UnionType,
+    AnyType, ArrayType, BaseType, BooleanType, BytesType, CoercionType, CustomType, DateTimeType,
+    DateType, DecimalType, DictionaryType, DiscriminatedUnionType, EntityType, EnumType, FloatType,
+    IntegerType, LiteralType, OptionalType, RecursionHolder, SetType, StringType, TimeDeltaType,
+    TimeType, TupleType, TypedDictType, UUIDType, UnionType, ValidatedType, VarTupleType,
 };
 
 #[derive(Clone, Debug)]
@@ -21,19 +21,21 @@ pub enum Type<'a, Base = Bound<'a, BaseType>> {
     Uuid(Bound<'a, UUIDType>, Base),
     #[allow(dead_code)]
     Bytes(Bound<'a, BytesType>, Base),
-    #[allow(dead_code)]
     Time(Bound<'a, TimeType>, Base),
-    #[allow(dead_code)]
     DateTime(Bound<'a, DateTimeType>, Base),
-    #[allow(dead_code)]
     Date(Bound<'a, DateType>, Base),
+    TimeDelta(Bound<'a, TimeDeltaType>, Base),
     Entity(Bound<'a, EntityType>, Base, usize),
     TypedDict(Bound<'a, TypedDictType>, Base, usize),
     Array(Bound<'a, ArrayType>, Base),
     Enum(Bound<'a, EnumType>, Base),
     Optional(Bound<'a, OptionalType>, Base),
+    Validated(Bound<'a, ValidatedType>, Base),
+    Coercion(Bound<'a, CoercionType>, Base),
     Dictionary(Bound<'a, DictionaryType>, Base),
     Tuple(Bound<'a, TupleType>, Base),
+    VarTuple(Bound<'a, VarTupleType>, Base),
+    Set(Bound<'a, SetType>, Base),
     DiscriminatedUnion(Bound<'a, DiscriminatedUnionType>, Base),
     Union(Bound<'a, UnionType>, Base),
     Literal(Bound<'a, LiteralType>, Base),
@@ -55,11 +57,16 @@ pub fn get_object_type<'a>(type_info: &Bound<'a, PyAny>) -> PyResult<Type<'a>> {
     check_type!(type_info, base_type, Time, TimeType);
     check_type!(type_info, base_type, DateTime, DateTimeType);
     check_type!(type_info, base_type, Date, DateType);
+    check_type!(type_info, base_type, TimeDelta, TimeDeltaType);
     check_type!(type_info, base_type, Enum, EnumType);
     check_type!(type_info, base_type, Optional, OptionalType);
+    check_type!(type_info, base_type, Validated, ValidatedType);
+    check_type!(type_info, base_type, Coercion, CoercionType);
     check_type!(type_info, base_type, Array, ArrayType);
     check_type!(type_info, base_type, Dictionary, DictionaryType);
     check_type!(type_info, base_type, Tuple, TupleType);
+    check_type!(type_info, base_type, VarTuple, VarTupleType);
+    check_type!(type_info, base_type, Set, SetType);
     check_type!(type_info, base_type, Any, AnyType);
     check_type!(type_info, base_type, Union, UnionType);
     check_type!(
@@ -1,4 +1,5 @@
 mod encoders;
+mod json;
 mod main;
 
 pub use main::Serializer;
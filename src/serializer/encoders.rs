@@ -5,28 +5,40 @@ use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 use dyn_clone::{clone_trait_object, DynClone};
-use pyo3::exceptions::PyRuntimeError;
+use num_bigint::BigInt;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyBytes, PyDate, PyDateTime, PyDict, PyFloat, PyList, PyLong, PySequence, PyString,
-    PyTime,
+    PyBool, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyList, PyLong,
+    PySequence, PySet, PyString, PyTime, PyTuple,
 };
 use pyo3::{intern, Bound, Py, PyAny, PyResult};
+use regex::Regex;
 use uuid::Uuid;
 
-use crate::errors::{ToPyErr, ValidationError};
+use crate::errors::{SchemaValidationError, ToPyErr, ValidationError};
 use crate::python::{
-    create_py_dict_known_size, create_py_list, create_py_tuple, dump_date, dump_datetime,
-    dump_time, parse_date, parse_datetime, parse_time, py_dict_set_item, py_list_get_item,
-    py_list_set_item, py_tuple_set_item,
+    create_py_dict_known_size, create_py_list, create_py_tuple, date_from_timestamp,
+    date_to_timestamp, datetime_from_timestamp, datetime_to_timestamp, datetime_to_utc,
+    datetime_with_tzinfo, dump_date, dump_datetime, dump_time, dump_timedelta, fmt_py, is_aware,
+    parse_date, parse_datetime, parse_time, parse_timedelta, py_dict_set_item, py_list_get_item,
+    py_list_set_item, py_tuple_set_item, timedelta_from_microseconds, timedelta_to_microseconds,
+    DateFormat,
 };
+use crate::validator::formats::StringFormat;
 use crate::validator::types::{DecimalType, FloatType, IntegerType, StringType};
 use crate::validator::validators::{
-    check_bounds, check_length, check_sequence_bounds, check_sequence_size, invalid_enum_item,
+    _check_bounds, check_bounds, check_decimal_digits, check_format, check_length, check_pattern,
+    check_sequence_bounds, check_sequence_size, check_unique_items, invalid_enum_item,
     invalid_type, invalid_type_dump, missing_required_property, no_encoder_for_discriminator,
-    str_as_bool,
+    read_only_property, str_as_bool, unexpected_property, DecimalValue,
 };
-use crate::validator::{map_py_err_to_schema_validation_error, Context, InstancePath};
+use crate::validator::{
+    map_py_err_to_schema_validation_error, raise_error, CoercionMode, Context, ErrorCollector,
+    InstancePath, UnionErrors,
+};
+
+use super::json::{json_to_py, write_py_key, write_py_value, write_raw, write_str, JsonValue};
 
 pub type TEncoder = dyn Encoder + Send + Sync;
 
@@ -39,6 +51,25 @@ pub trait Encoder: DynClone + Debug {
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>>;
 
+    /// Writes the dumped value as JSON into `buf`.
+    /// By default, the result of `dump` is written as is.
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        write_py_value(&self.dump(value)?, buf)
+    }
+
+    /// Loads the value from a parsed JSON document, the document is parsed into a complete tree first.
+    /// By default, the JSON value is converted to a python object and passed to `load`,
+    /// container encoders override it to avoid building intermediate python containers.
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.load(&json_to_py(py, value)?, instance_path, ctx)
+    }
+
     fn as_container_encoder(&self) -> Option<&dyn ContainerEncoder> {
         None
     }
@@ -53,12 +84,12 @@ pub struct EncoderField<'a> {
 }
 
 pub enum QueryFields<'a> {
-    Object(Vec<EncoderField<'a>>),
-    Dict(bool), // is_sequence
+    Object(Vec<EncoderField<'a>>, bool), // are extra values sequences
+    Dict(bool),                          // is_sequence
 }
 
 pub trait ContainerEncoder: Encoder {
-    fn get_fields(&self) -> QueryFields;
+    fn get_fields(&self) -> QueryFields<'_>;
 }
 
 clone_trait_object!(Encoder);
@@ -102,14 +133,31 @@ impl Encoder for IntEncoder {
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
         if let Ok(val) = value.downcast::<PyLong>() {
-            check_bounds!(val.extract()?, self.type_info, instance_path)?;
+            if ctx.is_strict() && value.is_instance_of::<PyBool>() {
+                invalid_type!("integer", value, instance_path)
+            }
+            if self.has_bounds() {
+                self.check_bounds(&val.extract()?, instance_path)?;
+            }
             return Ok(value.clone());
         }
-        if ctx.try_cast_from_string {
+        if ctx.is_lax() {
+            if let Ok(val) = value.downcast::<PyFloat>() {
+                let val = val.value();
+                if val.is_finite() && val.fract() == 0.0 {
+                    let result = value.py().get_type_bound::<PyLong>().call1((val,))?;
+                    if self.has_bounds() {
+                        self.check_bounds(&result.extract()?, instance_path)?;
+                    }
+                    return Ok(result);
+                }
+            }
+        }
+        if ctx.cast_from_string() {
             if let Ok(val) = value.downcast::<PyString>() {
-                if let Ok(val) = val.to_str()?.parse::<i64>() {
-                    check_bounds!(val, self.type_info, instance_path)?;
-                    return Ok(val.to_object(value.py()).into_bound(value.py()));
+                if let Some(val) = parse_int(val.to_str()?) {
+                    self.check_bounds(&val, instance_path)?;
+                    return Ok(val.into_py(value.py()).into_bound(value.py()));
                 }
             }
         }
@@ -117,6 +165,34 @@ impl Encoder for IntEncoder {
     }
 }
 
+/// Parses an optionally signed decimal integer, digit separators (`1_000`) are rejected.
+#[inline]
+fn parse_int(value: &str) -> Option<BigInt> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+impl IntEncoder {
+    #[inline]
+    fn has_bounds(&self) -> bool {
+        self.type_info.min.is_some() || self.type_info.max.is_some()
+    }
+
+    /// Python ints are unbounded, so the value and the bounds are compared as big integers.
+    #[inline]
+    fn check_bounds(&self, val: &BigInt, instance_path: &InstancePath) -> PyResult<()> {
+        _check_bounds(
+            val,
+            self.type_info.min.as_ref(),
+            self.type_info.max.as_ref(),
+            instance_path,
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatEncoder {
     pub(crate) type_info: FloatType,
@@ -135,14 +211,16 @@ impl Encoder for FloatEncoder {
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
         if let Ok(val) = value.downcast::<PyLong>() {
-            check_bounds!(val.extract()?, self.type_info, instance_path)?;
-            return Ok(value.clone());
+            if !ctx.is_strict() {
+                check_bounds!(val.extract()?, self.type_info, instance_path)?;
+                return Ok(value.clone());
+            }
         }
         if let Ok(val) = value.downcast::<PyFloat>() {
             check_bounds!(val.extract()?, self.type_info, instance_path)?;
             return Ok(value.clone());
         }
-        if ctx.try_cast_from_string {
+        if ctx.cast_from_string() {
             if let Ok(val) = value.downcast::<PyString>() {
                 if let Ok(val) = val.to_str()?.parse::<f64>() {
                     check_bounds!(val, self.type_info, instance_path)?;
@@ -152,56 +230,172 @@ impl Encoder for FloatEncoder {
         }
         invalid_type!("number", value, instance_path)
     }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            // JSON doesn't distinguish ints and floats, so integral numbers are floats in any coercion mode
+            JsonValue::Number(_) if ctx.mode == CoercionMode::Strict => {
+                let ctx = Context {
+                    mode: CoercionMode::Default,
+                    ..ctx.clone()
+                };
+                self.load(&json_to_py(py, value)?, instance_path, &ctx)
+            }
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct DecimalEncoder {
     pub(crate) type_info: DecimalType,
     pub(crate) decimal_cls: Py<PyAny>,
+    /// `min` / `max` of the type converted to `Decimal`
+    pub(crate) min: Option<Py<PyAny>>,
+    pub(crate) max: Option<Py<PyAny>>,
+    /// `Decimal` with the exponent of `decimal_places`, loaded values are rounded to it
+    pub(crate) quantum: Option<Py<PyAny>>,
 }
 
 impl Encoder for DecimalEncoder {
     #[inline]
     fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        if self.type_info.dump_as_number {
+            // The `Decimal` itself, converting it to float loses precision
+            return Ok(value.clone());
+        }
         Ok(value.str()?.into_any())
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        let str_value = value.str()?;
+        if !self.type_info.dump_as_number {
+            write_str(buf, str_value.to_str()?);
+        } else if value
+            .call_method0(intern!(value.py(), "is_finite"))?
+            .is_truthy()?
+        {
+            // Exact decimal representation, `Decimal.__str__` output is a valid JSON number
+            write_raw(buf, str_value.to_str()?);
+        } else {
+            // NaN and infinity are written as null, the same way as floats.
+            write_raw(buf, "null");
+        }
+        Ok(())
+    }
+
     #[inline]
     fn load<'a>(
         &self,
         value: &Bound<'a, PyAny>,
         instance_path: &InstancePath,
-        _ctx: &Context,
+        ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
-        let valid = if let Ok(val) = value.downcast::<PyFloat>() {
-            check_bounds!(val.value(), self.type_info, instance_path)?;
-            true
-        } else if let Ok(val) = value.downcast::<PyLong>() {
-            check_bounds!(val.extract()?, self.type_info, instance_path)?;
+        let valid = if ctx.is_exact() {
+            false
+        } else if let Ok(val) = value.downcast::<PyBool>() {
+            if ctx.is_strict() {
+                invalid_type!("decimal", value, instance_path)
+            }
+            // `Decimal("True")` is invalid, bools are loaded as `0` / `1` the same way as for ints
+            let val = i64::from(val.is_true()).into_py(value.py());
+            return self.load_decimal(val.bind(value.py()), true, instance_path);
+        } else if value.is_instance_of::<PyFloat>() || value.is_instance_of::<PyLong>() {
             true
         } else if let Ok(val) = value.downcast::<PyString>() {
-            match val.to_str()?.parse::<f64>() {
-                Ok(val_f64) => {
-                    check_bounds!(val_f64, self.type_info, instance_path)?;
-                    true
-                }
-                Err(_) => false,
-            }
+            !ctx.is_strict() && val.to_str()?.parse::<f64>().is_ok()
         } else {
             false
         };
-        if valid {
+        self.load_decimal(value, valid, instance_path)
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            // Keep all digits of the number, converting it to float first loses precision.
+            // JSON numbers are valid decimals in any coercion mode except the exact one.
+            JsonValue::Number(val) if !ctx.is_exact() => {
+                let value = PyString::new_bound(py, val.as_str());
+                self.load_decimal(value.as_any(), true, instance_path)
+            }
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
+        }
+    }
+}
+
+impl DecimalEncoder {
+    #[inline]
+    fn load_decimal<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        valid: bool,
+        instance_path: &InstancePath,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let py = value.py();
+        let decimal_cls = self.decimal_cls.bind(py);
+        let mut result = if value.is_instance(decimal_cls)? {
+            value.clone()
+        } else if valid {
             let str_value = value.str().expect("Failed to convert value to string.");
-            self.decimal_cls.bind(value.py()).call1((str_value,))
+            decimal_cls.call1((str_value,))?
         } else {
             invalid_type!("decimal", value, instance_path)
+        };
+        if let (Some(quantum), Some(rounding)) = (&self.quantum, &self.type_info.rounding) {
+            match result.call_method1(intern!(py, "quantize"), (quantum, rounding)) {
+                Ok(rounded) => result = rounded,
+                Err(_) => raise_error(
+                    format!(
+                        "{} cannot be rounded to {} decimal places",
+                        result,
+                        self.type_info.decimal_places.unwrap_or_default()
+                    ),
+                    instance_path,
+                )?,
+            }
+        }
+        if self.min.is_some() || self.max.is_some() {
+            // NaN is neither less nor greater than the bounds, so non-finite values are rejected
+            if !result.call_method0(intern!(py, "is_finite"))?.is_truthy()? {
+                raise_error(format!("{} is not a finite number", result), instance_path)?;
+            }
+            _check_bounds(
+                DecimalValue(&result),
+                self.min.as_ref().map(|min| DecimalValue(min.bind(py))),
+                self.max.as_ref().map(|max| DecimalValue(max.bind(py))),
+                instance_path,
+            )?;
         }
+        check_decimal_digits(
+            &result,
+            self.type_info.max_digits,
+            self.type_info.decimal_places,
+            instance_path,
+        )?;
+        Ok(result)
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct StringEncoder {
     pub(crate) type_info: StringType,
+    pub(crate) pattern: Option<Regex>,
+    pub(crate) format: Option<StringFormat>,
 }
 
 impl Encoder for StringEncoder {
@@ -224,6 +418,8 @@ impl Encoder for StringEncoder {
                 self.type_info.max_length,
                 instance_path,
             )?;
+            check_pattern(val, self.pattern.as_ref(), instance_path)?;
+            check_format(val, self.format, instance_path)?;
             Ok(value.clone())
         } else {
             invalid_type!("string", value, instance_path)
@@ -250,7 +446,16 @@ impl Encoder for BooleanEncoder {
         if let Ok(_val) = value.downcast::<PyBool>() {
             return Ok(value.clone());
         }
-        if ctx.try_cast_from_string {
+        if ctx.is_lax() {
+            if let Ok(val) = value.downcast::<PyLong>() {
+                match val.extract::<i64>() {
+                    Ok(0) => return Ok(false.to_object(value.py()).into_bound(value.py())),
+                    Ok(1) => return Ok(true.to_object(value.py()).into_bound(value.py())),
+                    _ => {}
+                }
+            }
+        }
+        if ctx.cast_from_string() {
             if let Ok(val) = value.downcast::<PyString>() {
                 if let Some(val) = str_as_bool(val.to_str()?) {
                     return Ok(val.to_object(value.py()).into_bound(value.py()));
@@ -311,6 +516,39 @@ impl Encoder for DictionaryEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            buf.push(b'{');
+            let mut first = true;
+            for (k, v) in dict.iter() {
+                let dump_result = if self.omit_none {
+                    let dump_result = self.value_encoder.dump(&v)?;
+                    if dump_result.is_none() {
+                        continue;
+                    }
+                    Some(dump_result)
+                } else {
+                    None
+                };
+                if !first {
+                    buf.push(b',');
+                }
+                first = false;
+                write_py_key(&self.key_encoder.dump(&k)?, buf)?;
+                buf.push(b':');
+                match dump_result {
+                    Some(dump_result) => write_py_value(&dump_result, buf)?,
+                    None => self.value_encoder.dump_json(&v, buf)?,
+                }
+            }
+            buf.push(b'}');
+            Ok(())
+        } else {
+            invalid_type_dump!("dict", value)
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -319,26 +557,59 @@ impl Encoder for DictionaryEncoder {
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
         if let Ok(val) = value.downcast::<PyDict>() {
-            let result_dict = create_py_dict_known_size(val.py(), val.len());
+            let py = val.py();
+            let result_dict = create_py_dict_known_size(py, val.len());
+            let mut errors = ErrorCollector::new(ctx);
             for (k, v) in val.iter() {
                 let instance_path = instance_path.push(&k);
-                let key = self.key_encoder.load(&k, &instance_path, ctx)?;
-                let value = self.value_encoder.load(&v, &instance_path, ctx)?;
-                py_dict_set_item(&result_dict, key.as_ptr(), value)?;
+                let key = errors.collect(py, self.key_encoder.load(&k, &instance_path, ctx))?;
+                let value = errors.collect(py, self.value_encoder.load(&v, &instance_path, ctx))?;
+                if let (Some(key), Some(value)) = (key, value) {
+                    py_dict_set_item(&result_dict, key.as_ptr(), value)?;
+                }
             }
+            errors.finish(py)?;
             Ok(result_dict.into_any())
         } else {
             invalid_type_dump!("dict", value)
         }
     }
 
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if let JsonValue::Object(map) = value {
+            let result_dict = create_py_dict_known_size(py, map.len());
+            let mut errors = ErrorCollector::new(ctx);
+            for (k, v) in map {
+                let k = PyString::new_bound(py, k).into_any();
+                let instance_path = instance_path.push(&k);
+                let key = errors.collect(py, self.key_encoder.load(&k, &instance_path, ctx))?;
+                let value =
+                    errors.collect(py, self.value_encoder.load_json(py, v, &instance_path, ctx))?;
+                if let (Some(key), Some(value)) = (key, value) {
+                    py_dict_set_item(&result_dict, key.as_ptr(), value)?;
+                }
+            }
+            errors.finish(py)?;
+            Ok(result_dict.into_any())
+        } else {
+            self.load(&json_to_py(py, value)?, instance_path, ctx)
+        }
+    }
+
     fn as_container_encoder(&self) -> Option<&dyn ContainerEncoder> {
         Some(self)
     }
 }
 
 impl ContainerEncoder for DictionaryEncoder {
-    fn get_fields(&self) -> QueryFields {
+    fn get_fields(&self) -> QueryFields<'_> {
         QueryFields::Dict(self.value_encoder.is_sequence())
     }
 }
@@ -369,6 +640,24 @@ impl Encoder for ArrayEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if let Ok(list) = value.downcast::<PyList>() {
+            buf.push(b'[');
+            for index in 0..list.len() {
+                if index > 0 {
+                    buf.push(b',');
+                }
+                self.encoder
+                    .dump_json(&py_list_get_item(list, index), buf)?;
+            }
+            buf.push(b']');
+            Ok(())
+        } else {
+            invalid_type_dump!("list", value)
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -379,26 +668,62 @@ impl Encoder for ArrayEncoder {
         if let Ok(val) = value.downcast::<PyList>() {
             let size = val.len();
             check_sequence_bounds(
-                val,
+                val.as_any(),
                 size,
                 self.min_length,
                 self.max_length,
                 Some(instance_path),
             )?;
             let result = create_py_list(value.py(), size);
+            let mut errors = ErrorCollector::new(ctx);
 
             for index in 0..size {
                 let item = py_list_get_item(val, index);
                 let instance_path = instance_path.push(index);
-                let val = self.encoder.load(&item, &instance_path, ctx)?;
-                py_list_set_item(&result, index, val);
+                let val =
+                    errors.collect(value.py(), self.encoder.load(&item, &instance_path, ctx))?;
+                if let Some(val) = val {
+                    py_list_set_item(&result, index, val);
+                }
             }
+            errors.finish(value.py())?;
             Ok(result.into_any())
         } else {
             invalid_type!("list", value, instance_path)
         }
     }
 
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            JsonValue::Array(items)
+                if self.min_length.is_none_or(|min| items.len() >= min)
+                    && self.max_length.is_none_or(|max| items.len() <= max) =>
+            {
+                let result = create_py_list(py, items.len());
+                let mut errors = ErrorCollector::new(ctx);
+                for (index, item) in items.iter().enumerate() {
+                    let instance_path = instance_path.push(index);
+                    let val = errors
+                        .collect(py, self.encoder.load_json(py, item, &instance_path, ctx))?;
+                    if let Some(val) = val {
+                        py_list_set_item(&result, index, val);
+                    }
+                }
+                errors.finish(py)?;
+                Ok(result.into_any())
+            }
+            // Invalid values are reported by `load`
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
+        }
+    }
+
     fn is_sequence(&self) -> bool {
         true
     }
@@ -409,7 +734,13 @@ pub struct EntityEncoder {
     pub(crate) cls: Py<PyAny>,
     pub(crate) omit_none: bool,
     pub(crate) is_frozen: bool,
+    pub(crate) forbid_extra: bool,
+    pub(crate) call_post_init: bool,
+    pub(crate) use_constructor: bool,
+    pub(crate) post_init_args: Py<PyTuple>,
+    pub(crate) validators: Vec<Py<PyAny>>,
     pub(crate) fields: Vec<Field>,
+    pub(crate) extra_fields: Option<Field>,
     pub(crate) create_object: Py<PyAny>,
     pub(crate) object_set_attr: Py<PyAny>,
 }
@@ -421,26 +752,237 @@ pub struct Field {
     pub(crate) dict_key_rs: String,
     pub(crate) encoder: Box<TEncoder>,
     pub(crate) required: bool,
+    /// Dumped only, the input value is ignored on load
+    pub(crate) read_only: bool,
+    /// Loaded only, never dumped
+    pub(crate) write_only: bool,
+    /// Name of the constructor argument, `None` for fields set after the constructor call
+    pub(crate) init_arg: Option<Py<PyString>>,
     pub(crate) default: Option<Py<PyAny>>,
     pub(crate) default_factory: Option<Py<PyAny>>,
 }
 
+#[inline]
+fn is_field_key(fields: &[Field], key: &str) -> bool {
+    fields.iter().any(|field| field.dict_key_rs == key)
+}
+
+/// Keys of read-only fields are not expected on load.
+#[inline]
+fn check_loaded_field_key(
+    fields: &[Field],
+    key: &str,
+    instance_path: &InstancePath,
+) -> PyResult<()> {
+    match fields.iter().find(|field| field.dict_key_rs == key) {
+        Some(field) if field.read_only => Err(read_only_property(key, instance_path)),
+        Some(_) => Ok(()),
+        None => Err(unexpected_property(key, instance_path)),
+    }
+}
+
+/// Reports every key of the input dict that doesn't match any field.
+#[inline]
+fn check_extra_keys(
+    fields: &[Field],
+    value: &Bound<'_, PyDict>,
+    instance_path: &InstancePath,
+    errors: &mut ErrorCollector,
+) -> PyResult<()> {
+    for key in value.keys() {
+        let key = key.str()?;
+        errors.collect(
+            value.py(),
+            check_loaded_field_key(fields, key.to_str()?, instance_path),
+        )?;
+    }
+    Ok(())
+}
+
+#[inline]
+fn check_extra_json_keys(
+    py: Python<'_>,
+    fields: &[Field],
+    value: &serde_json::Map<String, JsonValue>,
+    instance_path: &InstancePath,
+    errors: &mut ErrorCollector,
+) -> PyResult<()> {
+    for key in value.keys() {
+        errors.collect(py, check_loaded_field_key(fields, key, instance_path))?;
+    }
+    Ok(())
+}
+
+/// Loads the keys of the input dict that don't match any field with the encoder of the extra fields.
+#[inline]
+fn load_extra_keys<'py>(
+    fields: &[Field],
+    extra_fields: &Field,
+    value: &Bound<'py, PyDict>,
+    instance_path: &InstancePath,
+    ctx: &Context,
+) -> PyResult<Bound<'py, PyAny>> {
+    let extra = PyDict::new_bound(value.py());
+    for (key, val) in value.iter() {
+        if !is_field_key(fields, key.str()?.to_str()?) {
+            extra.set_item(key, val)?;
+        }
+    }
+    extra_fields
+        .encoder
+        .load(extra.as_any(), instance_path, ctx)
+}
+
+#[inline]
+fn load_extra_json_keys<'py>(
+    py: Python<'py>,
+    fields: &[Field],
+    extra_fields: &Field,
+    value: &serde_json::Map<String, JsonValue>,
+    instance_path: &InstancePath,
+    ctx: &Context,
+) -> PyResult<Bound<'py, PyAny>> {
+    let extra = value
+        .iter()
+        .filter(|(key, _)| !is_field_key(fields, key))
+        .map(|(key, val)| (key.clone(), val.clone()))
+        .collect();
+    extra_fields
+        .encoder
+        .load_json(py, &JsonValue::Object(extra), instance_path, ctx)
+}
+
+/// Whether the extra fields dict has sequence values, e.g. `dict[str, list[int]]`.
+#[inline]
+fn has_sequence_values(extra_fields: &Option<Field>) -> bool {
+    extra_fields
+        .as_ref()
+        .and_then(|f| f.encoder.as_container_encoder())
+        .is_some_and(|encoder| matches!(encoder.get_fields(), QueryFields::Dict(true)))
+}
+
+/// Merges the dumped extra fields into the output dict, keys of the regular fields take precedence.
+#[inline]
+fn dump_extra_keys(
+    fields: &[Field],
+    extra_fields: &Field,
+    value: &Bound<'_, PyAny>,
+    dict: &Bound<'_, PyDict>,
+) -> PyResult<()> {
+    let extra = extra_fields.encoder.dump(value)?;
+    for (key, val) in extra.downcast::<PyDict>()?.iter() {
+        if !is_field_key(fields, key.str()?.to_str()?) {
+            dict.set_item(key, val)?;
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+fn dump_extra_json_keys(
+    fields: &[Field],
+    extra_fields: &Field,
+    value: &Bound<'_, PyAny>,
+    mut first: bool,
+    buf: &mut Vec<u8>,
+) -> PyResult<()> {
+    let extra = extra_fields.encoder.dump(value)?;
+    for (key, val) in extra.downcast::<PyDict>()?.iter() {
+        if !is_field_key(fields, key.str()?.to_str()?) {
+            if !first {
+                buf.push(b',');
+            }
+            first = false;
+            write_py_key(&key, buf)?;
+            buf.push(b':');
+            write_py_value(&val, buf)?;
+        }
+    }
+    Ok(())
+}
+
+impl Field {
+    #[inline]
+    fn load_default<'py>(
+        &self,
+        py: Python<'py>,
+        instance_path: &InstancePath,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match (&self.default, &self.default_factory) {
+            (Some(val), _) => Ok(val.bind(py).clone()),
+            (_, Some(val)) => val.bind(py).call0(),
+            (None, _) => Err(missing_required_property(&self.dict_key_rs, instance_path)),
+        }
+    }
+
+    /// Writes the `"key":value` pair of the field, returns `true` if nothing was written.
+    #[inline]
+    fn dump_json(
+        &self,
+        value: &Bound<'_, PyAny>,
+        omit_none: bool,
+        first: bool,
+        buf: &mut Vec<u8>,
+    ) -> PyResult<bool> {
+        if !self.required && omit_none {
+            // We have to know the dumped value before writing the key
+            let dump_result = self.encoder.dump(value)?;
+            if dump_result.is_none() {
+                return Ok(first);
+            }
+            self.write_key(first, buf);
+            return write_py_value(&dump_result, buf).map(|_| false);
+        }
+        self.write_key(first, buf);
+        self.encoder.dump_json(value, buf).map(|_| false)
+    }
+
+    #[inline]
+    fn write_key(&self, first: bool, buf: &mut Vec<u8>) {
+        if !first {
+            buf.push(b',');
+        }
+        write_str(buf, &self.dict_key_rs);
+        buf.push(b':');
+    }
+}
+
 impl Encoder for EntityEncoder {
     #[inline]
     fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
         let dict = create_py_dict_known_size(value.py(), self.fields.len());
 
-        for field in &self.fields {
+        for field in self.fields.iter().filter(|field| !field.write_only) {
             let field_val = value.getattr(&field.name)?;
             let dump_result = field.encoder.dump(&field_val)?;
             if field.required || !self.omit_none || !dump_result.is_none() {
                 py_dict_set_item(&dict, field.dict_key.as_ptr(), dump_result)?;
             }
         }
+        if let Some(extra_fields) = &self.extra_fields {
+            let field_val = value.getattr(&extra_fields.name)?;
+            dump_extra_keys(&self.fields, extra_fields, &field_val, &dict)?;
+        }
 
         Ok(dict.into_any())
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        buf.push(b'{');
+        let mut first = true;
+        for field in self.fields.iter().filter(|field| !field.write_only) {
+            let field_val = value.getattr(&field.name)?;
+            first = field.dump_json(&field_val, self.omit_none, first, buf)?;
+        }
+        if let Some(extra_fields) = &self.extra_fields {
+            let field_val = value.getattr(&extra_fields.name)?;
+            dump_extra_json_keys(&self.fields, extra_fields, &field_val, first, buf)?;
+        }
+        buf.push(b'}');
+        Ok(())
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -448,41 +990,83 @@ impl Encoder for EntityEncoder {
         instance_path: &InstancePath,
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
-        let py_frozen_object_set_attr = self.object_set_attr.bind(value.py());
+        let py = value.py();
         if let Ok(val) = value.downcast::<PyDict>() {
-            let obj = self
-                .create_object
-                .bind(value.py())
-                .call1((self.cls.bind(value.py()),))?;
+            let mut obj = self.new_object(py, ctx)?;
+            let mut errors = ErrorCollector::new(ctx);
             for field in &self.fields {
                 let val = match val.get_item(&field.dict_key)? {
-                    Some(val) => {
-                        let instance_path =
-                            instance_path.push(field.dict_key.bind(value.py()).as_any());
-                        field.encoder.load(&val, &instance_path, ctx)?
+                    Some(val) if !field.read_only => {
+                        let instance_path = instance_path.push(field.dict_key.bind(py).as_any());
+                        field.encoder.load(&val, &instance_path, ctx)
                     }
-                    None => match (&field.default, &field.default_factory) {
-                        (Some(val), _) => val.bind(value.py()).clone(),
-                        (_, Some(val)) => val.bind(value.py()).call0()?,
-                        (None, _) => {
-                            return Err(missing_required_property(
-                                &field.dict_key_rs,
-                                instance_path,
-                            ));
-                        }
-                    },
+                    _ => field.load_default(py, instance_path),
                 };
+                if let Some(val) = errors.collect(py, val)? {
+                    self.set_attr(&mut obj, field, val)?;
+                }
+            }
+            if let Some(extra_fields) = &self.extra_fields {
+                let extra = load_extra_keys(&self.fields, extra_fields, val, instance_path, ctx);
+                if let Some(extra) = errors.collect(py, extra)? {
+                    self.set_attr(&mut obj, extra_fields, extra)?;
+                }
+            } else if self.forbid_extra {
+                check_extra_keys(&self.fields, val, instance_path, &mut errors)?;
+            }
+            errors.finish(py)?;
+            if ctx.check_only {
+                return Ok(py.None().into_bound(py));
+            }
 
-                if self.is_frozen {
-                    py_frozen_object_set_attr.call1((&obj, &field.name, val))?;
-                } else {
-                    obj.setattr(&field.name, val)?;
+            let obj = self.init_object(py, obj, instance_path)?;
+            run_validators(&self.validators, obj, instance_path)
+        } else {
+            invalid_type!("object", value, instance_path)
+        }
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if let JsonValue::Object(map) = value {
+            let mut obj = self.new_object(py, ctx)?;
+            let mut errors = ErrorCollector::new(ctx);
+            for field in &self.fields {
+                let val = match map.get(&field.dict_key_rs) {
+                    Some(val) if !field.read_only => {
+                        let instance_path = instance_path.push(field.dict_key.bind(py).as_any());
+                        field.encoder.load_json(py, val, &instance_path, ctx)
+                    }
+                    _ => field.load_default(py, instance_path),
                 };
+                if let Some(val) = errors.collect(py, val)? {
+                    self.set_attr(&mut obj, field, val)?;
+                }
+            }
+            if let Some(extra_fields) = &self.extra_fields {
+                let extra =
+                    load_extra_json_keys(py, &self.fields, extra_fields, map, instance_path, ctx);
+                if let Some(extra) = errors.collect(py, extra)? {
+                    self.set_attr(&mut obj, extra_fields, extra)?;
+                }
+            } else if self.forbid_extra {
+                check_extra_json_keys(py, &self.fields, map, instance_path, &mut errors)?;
+            }
+            errors.finish(py)?;
+            if ctx.check_only {
+                return Ok(py.None().into_bound(py));
             }
 
-            Ok(obj)
+            let obj = self.init_object(py, obj, instance_path)?;
+            run_validators(&self.validators, obj, instance_path)
         } else {
-            invalid_type!("object", value, instance_path)
+            self.load(&json_to_py(py, value)?, instance_path, ctx)
         }
     }
 
@@ -491,8 +1075,101 @@ impl Encoder for EntityEncoder {
     }
 }
 
+/// Object being loaded, in constructor mode the loaded values are collected for the class constructor.
+enum LoadedObject<'py> {
+    Object(Bound<'py, PyAny>),
+    Constructor {
+        kwargs: Bound<'py, PyDict>,
+        /// Values of the fields that are not constructor arguments
+        attrs: Vec<(Py<PyString>, Bound<'py, PyAny>)>,
+    },
+}
+
+impl EntityEncoder {
+    /// Without the class constructor the object is created right away, except when only checking the value.
+    #[inline]
+    fn new_object<'py>(&self, py: Python<'py>, ctx: &Context) -> PyResult<LoadedObject<'py>> {
+        if self.use_constructor || ctx.check_only {
+            Ok(LoadedObject::Constructor {
+                kwargs: PyDict::new_bound(py),
+                attrs: vec![],
+            })
+        } else {
+            let obj = self.create_object.bind(py).call1((self.cls.bind(py),))?;
+            Ok(LoadedObject::Object(obj))
+        }
+    }
+
+    /// Runs the user defined initialization of the loaded object.
+    /// Validation errors raised there (e.g. by loads of other serializers) are kept as is.
+    #[inline]
+    fn init_object<'py>(
+        &self,
+        py: Python<'py>,
+        obj: LoadedObject<'py>,
+        instance_path: &InstancePath,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let result = match obj {
+            LoadedObject::Constructor { kwargs, attrs } => {
+                self.cls.bind(py).call((), Some(&kwargs)).and_then(|obj| {
+                    for (name, val) in attrs {
+                        self.set_object_attr(&obj, &name, val)?;
+                    }
+                    Ok(obj)
+                })
+            }
+            LoadedObject::Object(obj) if self.call_post_init => obj
+                .call_method1(intern!(py, "__post_init__"), self.post_init_args.bind(py))
+                .map(|_| obj),
+            LoadedObject::Object(obj) => return Ok(obj),
+        };
+        result.map_err(|err| {
+            if err.is_instance_of::<SchemaValidationError>(py) {
+                err
+            } else {
+                map_py_err_to_schema_validation_error(py, err, instance_path)
+            }
+        })
+    }
+
+    #[inline]
+    fn set_attr<'py>(
+        &self,
+        obj: &mut LoadedObject<'py>,
+        field: &Field,
+        val: Bound<'py, PyAny>,
+    ) -> PyResult<()> {
+        match obj {
+            LoadedObject::Constructor { kwargs, attrs } => match &field.init_arg {
+                Some(init_arg) => py_dict_set_item(kwargs, init_arg.as_ptr(), val),
+                None => {
+                    attrs.push((field.name.clone_ref(val.py()), val));
+                    Ok(())
+                }
+            },
+            LoadedObject::Object(obj) => self.set_object_attr(obj, &field.name, val),
+        }
+    }
+
+    #[inline]
+    fn set_object_attr(
+        &self,
+        obj: &Bound<'_, PyAny>,
+        name: &Py<PyString>,
+        val: Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        if self.is_frozen {
+            let py_frozen_object_set_attr = self.object_set_attr.bind(obj.py());
+            py_frozen_object_set_attr.call1((obj, name, val))?;
+        } else {
+            obj.setattr(name, val)?;
+        }
+        Ok(())
+    }
+}
+
 impl ContainerEncoder for EntityEncoder {
-    fn get_fields(&self) -> QueryFields {
+    fn get_fields(&self) -> QueryFields<'_> {
         QueryFields::Object(
             self.fields
                 .iter()
@@ -501,6 +1178,7 @@ impl ContainerEncoder for EntityEncoder {
                     is_sequence: f.encoder.is_sequence(),
                 })
                 .collect(),
+            has_sequence_values(&self.extra_fields),
         )
     }
 }
@@ -508,7 +1186,9 @@ impl ContainerEncoder for EntityEncoder {
 #[derive(Debug, Clone)]
 pub struct TypedDictEncoder {
     pub(crate) omit_none: bool,
+    pub(crate) forbid_extra: bool,
     pub(crate) fields: Vec<Field>,
+    pub(crate) extra_fields: Option<Field>,
 }
 
 impl Encoder for TypedDictEncoder {
@@ -519,7 +1199,7 @@ impl Encoder for TypedDictEncoder {
             _ => invalid_type_dump!("dict", value),
         };
         let dict = create_py_dict_known_size(value.py(), self.fields.len());
-        for field in &self.fields {
+        for field in self.fields.iter().filter(|field| !field.write_only) {
             let field_val = match value.get_item(&field.name) {
                 Ok(Some(val)) => val,
                 _ => {
@@ -538,38 +1218,133 @@ impl Encoder for TypedDictEncoder {
                 py_dict_set_item(&dict, field.dict_key.as_ptr(), dump_result)?;
             }
         }
+        if let Some(extra_fields) = &self.extra_fields {
+            if let Some(field_val) = value.get_item(&extra_fields.name)? {
+                dump_extra_keys(&self.fields, extra_fields, &field_val, &dict)?;
+            }
+        }
         Ok(dict.into_any())
     }
 
     #[inline]
-    fn load<'a>(
-        &self,
-        value: &Bound<'a, PyAny>,
-        instance_path: &InstancePath,
-        ctx: &Context,
-    ) -> PyResult<Bound<'a, PyAny>> {
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
         let value = match value.downcast::<PyDict>() {
             Ok(val) => val,
-            Err(_) => {
-                invalid_type_dump!("dict", value);
-            }
+            _ => invalid_type_dump!("dict", value),
         };
-        let dict = create_py_dict_known_size(value.py(), self.fields.len());
-        for field in &self.fields {
-            let field_val = match value.get_item(&field.dict_key) {
+        buf.push(b'{');
+        let mut first = true;
+        for field in self.fields.iter().filter(|field| !field.write_only) {
+            let field_val = match value.get_item(&field.name) {
                 Ok(Some(val)) => val,
                 _ => {
                     if field.required {
-                        return Err(missing_required_property(&field.dict_key_rs, instance_path));
+                        return Err(ValidationError::new_err(format!(
+                            "data dictionary is missing required parameter {}",
+                            &field.name
+                        )));
                     } else {
                         continue;
                     }
                 }
             };
+            first = field.dump_json(&field_val, self.omit_none, first, buf)?;
+        }
+        if let Some(extra_fields) = &self.extra_fields {
+            if let Some(field_val) = value.get_item(&extra_fields.name)? {
+                dump_extra_json_keys(&self.fields, extra_fields, &field_val, first, buf)?;
+            }
+        }
+        buf.push(b'}');
+        Ok(())
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let value = match value.downcast::<PyDict>() {
+            Ok(val) => val,
+            Err(_) => {
+                invalid_type_dump!("dict", value);
+            }
+        };
+        let py = value.py();
+        let dict = create_py_dict_known_size(py, self.fields.len());
+        let mut errors = ErrorCollector::new(ctx);
+        for field in self.fields.iter().filter(|field| !field.read_only) {
+            let field_val = match value.get_item(&field.dict_key) {
+                Ok(Some(val)) => val,
+                _ => {
+                    if field.required {
+                        let err = missing_required_property(&field.dict_key_rs, instance_path);
+                        errors.collect::<()>(py, Err(err))?;
+                    }
+                    continue;
+                }
+            };
             let instance_path = instance_path.push(field.dict_key_rs.as_str());
-            let dump_result = field.encoder.load(&field_val, &instance_path, ctx)?;
-            py_dict_set_item(&dict, field.name.as_ptr(), dump_result)?;
+            let dump_result = field.encoder.load(&field_val, &instance_path, ctx);
+            if let Some(dump_result) = errors.collect(py, dump_result)? {
+                py_dict_set_item(&dict, field.name.as_ptr(), dump_result)?;
+            }
+        }
+        if let Some(extra_fields) = &self.extra_fields {
+            let extra = load_extra_keys(&self.fields, extra_fields, value, instance_path, ctx);
+            if let Some(extra) = errors.collect(py, extra)? {
+                py_dict_set_item(&dict, extra_fields.name.as_ptr(), extra)?;
+            }
+        } else if self.forbid_extra {
+            check_extra_keys(&self.fields, value, instance_path, &mut errors)?;
+        }
+        errors.finish(py)?;
+        Ok(dict.into_any())
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let map = match value {
+            JsonValue::Object(map) => map,
+            _ => return self.load(&json_to_py(py, value)?, instance_path, ctx),
+        };
+        let dict = create_py_dict_known_size(py, self.fields.len());
+        let mut errors = ErrorCollector::new(ctx);
+        for field in self.fields.iter().filter(|field| !field.read_only) {
+            let field_val = match map.get(&field.dict_key_rs) {
+                Some(val) => val,
+                None => {
+                    if field.required {
+                        let err = missing_required_property(&field.dict_key_rs, instance_path);
+                        errors.collect::<()>(py, Err(err))?;
+                    }
+                    continue;
+                }
+            };
+            let instance_path = instance_path.push(field.dict_key_rs.as_str());
+            let load_result = field.encoder.load_json(py, field_val, &instance_path, ctx);
+            if let Some(load_result) = errors.collect(py, load_result)? {
+                py_dict_set_item(&dict, field.name.as_ptr(), load_result)?;
+            }
+        }
+        if let Some(extra_fields) = &self.extra_fields {
+            let extra =
+                load_extra_json_keys(py, &self.fields, extra_fields, map, instance_path, ctx);
+            if let Some(extra) = errors.collect(py, extra)? {
+                py_dict_set_item(&dict, extra_fields.name.as_ptr(), extra)?;
+            }
+        } else if self.forbid_extra {
+            check_extra_json_keys(py, &self.fields, map, instance_path, &mut errors)?;
         }
+        errors.finish(py)?;
         Ok(dict.into_any())
     }
     fn as_container_encoder(&self) -> Option<&dyn ContainerEncoder> {
@@ -578,7 +1353,7 @@ impl Encoder for TypedDictEncoder {
 }
 
 impl ContainerEncoder for TypedDictEncoder {
-    fn get_fields(&self) -> QueryFields {
+    fn get_fields(&self) -> QueryFields<'_> {
         QueryFields::Object(
             self.fields
                 .iter()
@@ -587,6 +1362,7 @@ impl ContainerEncoder for TypedDictEncoder {
                     is_sequence: f.encoder.is_sequence(),
                 })
                 .collect(),
+            has_sequence_values(&self.extra_fields),
         )
     }
 }
@@ -645,7 +1421,7 @@ impl Encoder for EnumEncoder {
     ) -> PyResult<Bound<'a, PyAny>> {
         match self.load_map.bind(value.py()).get_item(value) {
             Ok(Some(val)) => Ok(val),
-            _ if ctx.try_cast_from_string => {
+            _ if ctx.cast_from_string() => {
                 if let Ok(Some(val)) = self.load_map.bind(value.py()).get_item((&value, false)) {
                     return Ok(val);
                 }
@@ -671,6 +1447,16 @@ impl Encoder for OptionalEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if value.is_none() {
+            write_raw(buf, "null");
+            Ok(())
+        } else {
+            self.encoder.dump_json(value, buf)
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -685,6 +1471,20 @@ impl Encoder for OptionalEncoder {
         }
     }
 
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            JsonValue::Null => Ok(py.None().into_bound(py)),
+            _ => self.encoder.load_json(py, value, instance_path, ctx),
+        }
+    }
+
     fn is_sequence(&self) -> bool {
         self.encoder.is_sequence()
     }
@@ -714,6 +1514,25 @@ impl Encoder for TupleEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if let Ok(seq) = value.downcast::<PySequence>() {
+            let seq_len = seq.len()?;
+            check_sequence_size(seq, seq_len, self.encoders.len(), None)?;
+            buf.push(b'[');
+            for index in 0..seq_len {
+                if index > 0 {
+                    buf.push(b',');
+                }
+                self.encoders[index].dump_json(&seq.get_item(index)?, buf)?;
+            }
+            buf.push(b']');
+            Ok(())
+        } else {
+            invalid_type_dump!("sequence", value)
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -729,39 +1548,94 @@ impl Encoder for TupleEncoder {
             let seq_len = seq.len()?;
             check_sequence_size(seq, seq_len, self.encoders.len(), Some(instance_path))?;
             let result = create_py_tuple(value.py(), seq_len);
+            let mut errors = ErrorCollector::new(ctx);
             for index in 0..seq_len {
                 let item = seq.get_item(index)?;
                 let instance_path = instance_path.push(index);
-                let val = self.encoders[index].load(&item, &instance_path, ctx)?;
-                py_tuple_set_item(&result, index, val);
+                let val = self.encoders[index].load(&item, &instance_path, ctx);
+                if let Some(val) = errors.collect(value.py(), val)? {
+                    py_tuple_set_item(&result, index, val);
+                }
             }
+            errors.finish(value.py())?;
             Ok(result.into_any())
         } else {
             invalid_type!("sequence", value, instance_path)
         }
     }
 
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            JsonValue::Array(items) if items.len() == self.encoders.len() => {
+                let result = create_py_tuple(py, items.len());
+                let mut errors = ErrorCollector::new(ctx);
+                for (index, (item, encoder)) in items.iter().zip(&self.encoders).enumerate() {
+                    let instance_path = instance_path.push(index);
+                    let val = encoder.load_json(py, item, &instance_path, ctx);
+                    if let Some(val) = errors.collect(py, val)? {
+                        py_tuple_set_item(&result, index, val);
+                    }
+                }
+                errors.finish(py)?;
+                Ok(result.into_any())
+            }
+            // Invalid values are reported by `load`
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
+        }
+    }
+
     fn is_sequence(&self) -> bool {
         true
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct UnionEncoder {
-    pub(crate) encoders: Vec<Box<TEncoder>>,
-    pub(crate) union_repr: String,
+pub struct VarTupleEncoder {
+    pub(crate) encoder: Box<TEncoder>,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
 }
 
-impl Encoder for UnionEncoder {
+impl Encoder for VarTupleEncoder {
     #[inline]
     fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
-        for encoder in &self.encoders {
-            let result = encoder.dump(value);
-            if result.is_ok() {
-                return result;
+        if let Ok(seq) = value.downcast::<PySequence>() {
+            let seq_len = seq.len()?;
+            let result = create_py_list(value.py(), seq_len);
+            for index in 0..seq_len {
+                let item = seq.get_item(index)?;
+                let val = self.encoder.dump(&item)?;
+                py_list_set_item(&result, index, val);
             }
+
+            Ok(result.into_any())
+        } else {
+            invalid_type_dump!("sequence", value)
+        }
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if let Ok(seq) = value.downcast::<PySequence>() {
+            buf.push(b'[');
+            for index in 0..seq.len()? {
+                if index > 0 {
+                    buf.push(b',');
+                }
+                self.encoder.dump_json(&seq.get_item(index)?, buf)?;
+            }
+            buf.push(b']');
+            Ok(())
+        } else {
+            invalid_type_dump!("sequence", value)
         }
-        invalid_type_dump!(&self.union_repr, value)
     }
 
     #[inline]
@@ -771,113 +1645,172 @@ impl Encoder for UnionEncoder {
         instance_path: &InstancePath,
         ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
-        for encoder in &self.encoders {
-            let result = encoder.load(value, instance_path, ctx);
-            if result.is_ok() {
-                return result;
+        // Check sequence is not str
+        if let Ok(seq) = value.downcast::<PySequence>() {
+            if value.is_instance_of::<PyString>() {
+                invalid_type!("sequence", value, instance_path);
+            }
+            let seq_len = seq.len()?;
+            check_sequence_bounds(
+                value,
+                seq_len,
+                self.min_length,
+                self.max_length,
+                Some(instance_path),
+            )?;
+            let result = create_py_tuple(value.py(), seq_len);
+            let mut errors = ErrorCollector::new(ctx);
+            for index in 0..seq_len {
+                let item = seq.get_item(index)?;
+                let instance_path = instance_path.push(index);
+                let val = self.encoder.load(&item, &instance_path, ctx);
+                if let Some(val) = errors.collect(value.py(), val)? {
+                    py_tuple_set_item(&result, index, val);
+                }
             }
+            errors.finish(value.py())?;
+            Ok(result.into_any())
+        } else {
+            invalid_type!("sequence", value, instance_path)
         }
-        invalid_type!(&self.union_repr, value, instance_path)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct DiscriminatorKey(String);
-
-impl TryFrom<&Bound<'_, PyAny>> for DiscriminatorKey {
-    type Error = ();
 
-    fn try_from(value: &Bound<'_, PyAny>) -> Result<Self, Self::Error> {
-        if let Ok(val) = value.downcast::<PyString>() {
-            Ok(DiscriminatorKey(val.to_string()))
-        } else if let Ok(value) = value.getattr(intern!(value.py(), "value")) {
-            DiscriminatorKey::try_from(&value)
-        } else {
-            Err(())
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            JsonValue::Array(items)
+                if self.min_length.is_none_or(|min| items.len() >= min)
+                    && self.max_length.is_none_or(|max| items.len() <= max) =>
+            {
+                let result = create_py_tuple(py, items.len());
+                let mut errors = ErrorCollector::new(ctx);
+                for (index, item) in items.iter().enumerate() {
+                    let instance_path = instance_path.push(index);
+                    let val = self.encoder.load_json(py, item, &instance_path, ctx);
+                    if let Some(val) = errors.collect(py, val)? {
+                        py_tuple_set_item(&result, index, val);
+                    }
+                }
+                errors.finish(py)?;
+                Ok(result.into_any())
+            }
+            // Invalid values are reported by `load`
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
         }
     }
-}
 
-impl fmt::Display for DiscriminatorKey {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+    fn is_sequence(&self) -> bool {
+        true
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct DiscriminatedUnionEncoder {
-    pub(crate) encoders: HashMap<DiscriminatorKey, Box<TEncoder>>,
-    pub(crate) dump_discriminator: Py<PyString>,
-    pub(crate) load_discriminator: Py<PyString>,
-    pub(crate) load_discriminator_rs: String,
-    pub(crate) keys: Vec<DiscriminatorKey>,
+pub struct SetEncoder {
+    pub(crate) encoder: Box<TEncoder>,
+    pub(crate) frozen: bool,
+    pub(crate) forbid_duplicates: bool,
+    pub(crate) sort_on_dump: bool,
 }
 
-impl Encoder for DiscriminatedUnionEncoder {
+impl SetEncoder {
     #[inline]
-    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
-        let key = match value.getattr(&self.dump_discriminator) {
-            Ok(val) => val,
-            Err(_) => {
-                return Err(missing_required_property(
-                    self.dump_discriminator.bind(value.py()).str()?.to_str()?,
-                    &InstancePath::new(),
-                ));
-            }
-        };
-
-        let key = DiscriminatorKey::try_from(&key)
-            .map_err(|_| no_encoder_for_discriminator(&key, &self.keys, &InstancePath::new()))?;
-
-        let encoder = self.encoders.get(&key).ok_or_else(|| {
-            let instance_path = InstancePath::new();
-            no_encoder_for_discriminator(&key, &self.keys, &instance_path)
-        })?;
-        encoder.dump(value)
+    fn is_set(value: &Bound<'_, PyAny>) -> bool {
+        value.is_instance_of::<PySet>() || value.is_instance_of::<PyFrozenSet>()
     }
 
+    /// Adds the loaded item, unhashable items are reported as validation errors.
     #[inline]
-    fn load<'a>(
-        &self,
-        value: &Bound<'a, PyAny>,
+    fn add_item(
+        result: &Bound<'_, PySet>,
+        item: Bound<'_, PyAny>,
         instance_path: &InstancePath,
-        ctx: &Context,
-    ) -> PyResult<Bound<'a, PyAny>> {
-        if let Ok(val) = value.downcast::<PyDict>() {
-            let key = match val.get_item(&self.load_discriminator) {
-                Ok(Some(k)) => k,
-                _ => {
-                    return Err(missing_required_property(
-                        &self.load_discriminator_rs,
-                        instance_path,
-                    ));
-                }
-            };
+    ) -> PyResult<()> {
+        match result.add(&item) {
+            Err(err) if err.is_instance_of::<PyTypeError>(result.py()) => {
+                raise_error(format!("{} is not hashable", fmt_py(&item)), instance_path)
+            }
+            result => result,
+        }
+    }
 
-            let key = DiscriminatorKey::try_from(&key).map_err(|_| {
-                no_encoder_for_discriminator(&key.to_string(), &self.keys, instance_path)
-            })?;
+    /// Sorts the dumped items by value, items that can't be compared with each other
+    /// (e.g. of different types) are sorted by type name and JSON representation instead.
+    #[inline]
+    fn sort_items(items: &Bound<'_, PyList>) -> PyResult<()> {
+        match items.sort() {
+            Err(err) if err.is_instance_of::<PyTypeError>(items.py()) => {}
+            result => return result,
+        }
+        let mut keyed = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            let mut json = Vec::new();
+            write_py_value(&item, &mut json)?;
+            keyed.push(((item.get_type().qualname()?.to_string(), json), item));
+        }
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (index, (_, item)) in keyed.into_iter().enumerate() {
+            items.set_item(index, item)?;
+        }
+        Ok(())
+    }
 
-            let encoder = self.encoders.get(&key).ok_or_else(|| {
-                let instance_path = instance_path.push(self.load_discriminator_rs.as_str());
-                no_encoder_for_discriminator(&key, &self.keys, &instance_path)
-            })?;
-            encoder.load(value, instance_path, ctx)
+    /// Converts the loaded items into the resulting `set` / `frozenset`.
+    #[inline]
+    fn finish<'py>(
+        &self,
+        result: Bound<'py, PySet>,
+        errors: ErrorCollector,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = result.py();
+        errors.finish(py)?;
+        if self.frozen {
+            py.get_type_bound::<PyFrozenSet>().call1((result,))
         } else {
-            invalid_type!("dict", value, instance_path)
+            Ok(result.into_any())
         }
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct TimeEncoder {}
-
-impl Encoder for TimeEncoder {
+impl Encoder for SetEncoder {
     #[inline]
     fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
-        let py_time = value.downcast::<PyTime>()?;
-        let result = dump_time(py_time)?;
-        Ok(result.into_py(value.py()).into_bound(value.py()))
+        if !Self::is_set(value) {
+            invalid_type_dump!("set", value)
+        }
+        let result = PyList::empty_bound(value.py());
+        for item in value.iter()? {
+            result.append(self.encoder.dump(&item?)?)?;
+        }
+        if self.sort_on_dump {
+            Self::sort_items(&result)?;
+        }
+        Ok(result.into_any())
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if self.sort_on_dump {
+            // Items can only be ordered after they are dumped to plain values
+            return write_py_value(&self.dump(value)?, buf);
+        }
+        if !Self::is_set(value) {
+            invalid_type_dump!("set", value)
+        }
+        buf.push(b'[');
+        for (index, item) in value.iter()?.enumerate() {
+            if index > 0 {
+                buf.push(b',');
+            }
+            self.encoder.dump_json(&item?, buf)?;
+        }
+        buf.push(b']');
+        Ok(())
     }
 
     #[inline]
@@ -885,65 +1818,929 @@ impl Encoder for TimeEncoder {
         &self,
         value: &Bound<'a, PyAny>,
         instance_path: &InstancePath,
-        _ctx: &Context,
+        ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
-        if let Ok(val) = value.downcast::<PyString>() {
-            if let Ok(result) = parse_time(value.py(), val.to_str()?) {
-                return Ok(result.into_any());
+        if !(value.is_instance_of::<PyList>()
+            || value.is_instance_of::<PyTuple>()
+            || Self::is_set(value))
+        {
+            invalid_type!("array", value, instance_path)
+        }
+        let py = value.py();
+        let result = PySet::empty_bound(py)?;
+        let mut errors = ErrorCollector::new(ctx);
+        let mut items_count = 0;
+        for (index, item) in value.iter()?.enumerate() {
+            let instance_path = instance_path.push(index);
+            let val = self
+                .encoder
+                .load(&item?, &instance_path, ctx)
+                .and_then(|val| Self::add_item(&result, val, &instance_path));
+            if errors.collect(py, val)?.is_some() {
+                items_count += 1;
             }
         }
-        invalid_type!("time", value, instance_path)
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct DateTimeEncoder {
-    pub(crate) naive_datetime_to_utc: bool,
-}
-
-impl Encoder for DateTimeEncoder {
-    #[inline]
-    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
-        let py_datetime = value.downcast::<PyDateTime>()?;
-        let result = dump_datetime(py_datetime, self.naive_datetime_to_utc)?;
-        Ok(result.into_py(value.py()).into_bound(value.py()))
+        if self.forbid_duplicates {
+            let check = check_unique_items(value, items_count, result.len(), instance_path);
+            errors.collect(py, check)?;
+        }
+        self.finish(result, errors)
     }
 
     #[inline]
-    fn load<'a>(
+    fn load_json<'py>(
         &self,
-        value: &Bound<'a, PyAny>,
+        py: Python<'py>,
+        value: &JsonValue,
         instance_path: &InstancePath,
-        _ctx: &Context,
-    ) -> PyResult<Bound<'a, PyAny>> {
-        if let Ok(val) = value.downcast::<PyString>() {
-            if let Ok(result) = parse_datetime(value.py(), val.to_str()?) {
-                return Ok(result.into_any());
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            JsonValue::Array(items) => {
+                let result = PySet::empty_bound(py)?;
+                let mut errors = ErrorCollector::new(ctx);
+                let mut items_count = 0;
+                for (index, item) in items.iter().enumerate() {
+                    let instance_path = instance_path.push(index);
+                    let val = self
+                        .encoder
+                        .load_json(py, item, &instance_path, ctx)
+                        .and_then(|val| Self::add_item(&result, val, &instance_path));
+                    if errors.collect(py, val)?.is_some() {
+                        items_count += 1;
+                    }
+                }
+                if self.forbid_duplicates && result.len() < items_count {
+                    // The python value is only built to report the error
+                    let value = json_to_py(py, value)?;
+                    let check =
+                        check_unique_items(&value, items_count, result.len(), instance_path);
+                    errors.collect(py, check)?;
+                }
+                self.finish(result, errors)
             }
+            // Invalid values are reported by `load`
+            _ => self.load(&json_to_py(py, value)?, instance_path, ctx),
         }
-        invalid_type!("datetime", value, instance_path)
+    }
+
+    fn is_sequence(&self) -> bool {
+        true
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct DateEncoder {}
-
-impl Encoder for DateEncoder {
-    #[inline]
+pub struct UnionEncoder {
+    pub(crate) encoders: Vec<Box<TEncoder>>,
+    pub(crate) union_repr: String,
+    /// Try all variants without coercion first, so the variant of the exact type wins.
+    pub(crate) smart: bool,
+    /// Map from the class of dumped values to the index of the variant encoder
+    pub(crate) dump_encoders: Py<PyDict>,
+}
+
+impl UnionEncoder {
+    /// Returns `None` if the class of the value is claimed by no variant or by several of them.
+    #[inline]
+    fn get_dump_encoder(&self, value: &Bound<'_, PyAny>) -> PyResult<Option<&TEncoder>> {
+        match self
+            .dump_encoders
+            .bind(value.py())
+            .get_item(value.get_type())?
+        {
+            Some(index) => Ok(Some(self.encoders[index.extract::<usize>()?].as_ref())),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the first loaded variant or the errors of all variants.
+    #[inline]
+    fn load_variant<'py>(
+        &self,
+        py: Python<'py>,
+        ctx: &Context,
+        load: impl Fn(&TEncoder, &Context) -> PyResult<Bound<'py, PyAny>>,
+    ) -> PyResult<Result<Bound<'py, PyAny>, UnionErrors>> {
+        // Errors of the variants that matched exactly but failed in user code, they aren't loaded again
+        let mut failed = vec![];
+        if self.smart {
+            let check_ctx = Context {
+                mode: CoercionMode::Exact,
+                check_only: true,
+                ..ctx.clone()
+            };
+            for (index, encoder) in self.encoders.iter().enumerate() {
+                match load(encoder.as_ref(), &check_ctx) {
+                    Ok(result) if ctx.check_only => return Ok(Ok(result)),
+                    Ok(_) => {}
+                    Err(err) if err.is_instance_of::<SchemaValidationError>(py) => continue,
+                    Err(err) => return Err(err),
+                }
+                let exact_ctx = Context {
+                    mode: CoercionMode::Exact,
+                    ..ctx.clone()
+                };
+                match load(encoder.as_ref(), &exact_ctx) {
+                    Ok(result) => return Ok(Ok(result)),
+                    Err(err) if err.is_instance_of::<SchemaValidationError>(py) => {
+                        failed.push((index, err))
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+        let mut errors = UnionErrors::default();
+        for (index, encoder) in self.encoders.iter().enumerate() {
+            if let Some(position) = failed.iter().position(|(failed, _)| *failed == index) {
+                errors.add(py, failed.swap_remove(position).1)?;
+                continue;
+            }
+            match load(encoder.as_ref(), ctx) {
+                Ok(result) => return Ok(Ok(result)),
+                Err(err) => errors.add(py, err)?,
+            }
+        }
+        Ok(Err(errors))
+    }
+
+    #[inline]
+    fn raise_invalid_type(
+        &self,
+        errors: UnionErrors,
+        value: &Bound<'_, PyAny>,
+        instance_path: &InstancePath,
+    ) -> PyResult<()> {
+        let message = format!(r#"{} is not of type "{}""#, fmt_py(value), self.union_repr);
+        errors.raise(value.py(), message, instance_path)
+    }
+}
+
+impl Encoder for UnionEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        if let Some(encoder) = self.get_dump_encoder(value)? {
+            return encoder.dump(value);
+        }
+        for encoder in &self.encoders {
+            let result = encoder.dump(value);
+            if result.is_ok() {
+                return result;
+            }
+        }
+        invalid_type_dump!(&self.union_repr, value)
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        if let Some(encoder) = self.get_dump_encoder(value)? {
+            return encoder.dump_json(value, buf);
+        }
+        let start = buf.len();
+        for encoder in &self.encoders {
+            if encoder.dump_json(value, buf).is_ok() {
+                return Ok(());
+            }
+            // Drop the partially written output of the failed variant
+            buf.truncate(start);
+        }
+        invalid_type_dump!(&self.union_repr, value)
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        match self.load_variant(value.py(), ctx, |encoder, ctx| {
+            encoder.load(value, instance_path, ctx)
+        })? {
+            Ok(result) => Ok(result),
+            Err(errors) => {
+                self.raise_invalid_type(errors, value, instance_path)?;
+                unreachable!()
+            }
+        }
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.load_variant(py, ctx, |encoder, ctx| {
+            encoder.load_json(py, value, instance_path, ctx)
+        })? {
+            Ok(result) => Ok(result),
+            Err(errors) => {
+                self.raise_invalid_type(errors, &json_to_py(py, value)?, instance_path)?;
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// Discriminator value, bools are kept apart from ints so `True` doesn't match `Literal[1]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiscriminatorKey {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+}
+
+impl DiscriminatorKey {
+    #[inline]
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        match value {
+            JsonValue::String(val) => Some(DiscriminatorKey::Str(val.clone())),
+            JsonValue::Bool(val) => Some(DiscriminatorKey::Bool(*val)),
+            JsonValue::Number(val) => val.as_i64().map(DiscriminatorKey::Int),
+            _ => None,
+        }
+    }
+
+    /// The value as it is shown in error messages, only strings are quoted.
+    #[inline]
+    fn repr(&self) -> String {
+        match self {
+            DiscriminatorKey::Str(val) => format!(r#""{}""#, val),
+            _ => self.to_string(),
+        }
+    }
+
+    #[inline]
+    fn to_json(&self) -> JsonValue {
+        match self {
+            DiscriminatorKey::Str(val) => JsonValue::String(val.clone()),
+            DiscriminatorKey::Int(val) => JsonValue::from(*val),
+            DiscriminatorKey::Bool(val) => JsonValue::Bool(*val),
+        }
+    }
+}
+
+impl TryFrom<&Bound<'_, PyAny>> for DiscriminatorKey {
+    type Error = ();
+
+    fn try_from(value: &Bound<'_, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(val) = value.downcast::<PyString>() {
+            Ok(DiscriminatorKey::Str(val.to_string()))
+        } else if let Ok(val) = value.downcast::<PyBool>() {
+            Ok(DiscriminatorKey::Bool(val.is_true()))
+        } else if let Ok(val) = value.downcast::<PyLong>() {
+            val.extract::<i64>()
+                .map(DiscriminatorKey::Int)
+                .map_err(|_| ())
+        } else if let Ok(value) = value.getattr(intern!(value.py(), "value")) {
+            DiscriminatorKey::try_from(&value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl ToPyObject for DiscriminatorKey {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            DiscriminatorKey::Str(val) => val.to_object(py),
+            DiscriminatorKey::Int(val) => val.to_object(py),
+            DiscriminatorKey::Bool(val) => val.to_object(py),
+        }
+    }
+}
+
+impl fmt::Display for DiscriminatorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiscriminatorKey::Str(val) => write!(f, "{}", val),
+            DiscriminatorKey::Int(val) => write!(f, "{}", val),
+            DiscriminatorKey::Bool(true) => write!(f, "True"),
+            DiscriminatorKey::Bool(false) => write!(f, "False"),
+        }
+    }
+}
+
+/// Where the discriminator value of a union variant is taken from.
+#[derive(Debug, Clone)]
+pub enum DiscriminatorSource {
+    /// Path to the field, nested fields are separated by dots in the annotation
+    Field(Vec<Py<PyString>>, Vec<String>),
+    /// Function returning the discriminator value
+    Callable(Py<PyAny>),
+}
+
+#[derive(Debug, Clone)]
+pub struct DiscriminatedUnionEncoder {
+    pub(crate) encoders: HashMap<DiscriminatorKey, Box<TEncoder>>,
+    pub(crate) dump_discriminator: DiscriminatorSource,
+    pub(crate) load_discriminator: DiscriminatorSource,
+    pub(crate) keys: Vec<DiscriminatorKey>,
+    /// Used for values without the discriminator field
+    pub(crate) default_key: Option<DiscriminatorKey>,
+    /// Used for values with unknown discriminator values
+    pub(crate) fallback: Option<Box<TEncoder>>,
+}
+
+impl Encoder for DiscriminatedUnionEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        self.get_dump_encoder(value)?.dump(value)
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        self.get_dump_encoder(value)?.dump_json(value, buf)
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let (names, names_rs) = match &self.load_discriminator {
+            DiscriminatorSource::Field(names, names_rs) => (names, names_rs),
+            DiscriminatorSource::Callable(func) => {
+                return self
+                    .get_callable_load_encoder(func, value, instance_path)?
+                    .load(value, instance_path, ctx);
+            }
+        };
+        if let Ok(val) = value.downcast::<PyDict>() {
+            let key = match get_load_key(val, names)? {
+                Some(k) => k,
+                None => {
+                    if let Some(key) = &self.default_key {
+                        // Load a copy with the default discriminator, so the variant gets a valid value
+                        let value = with_default_key(val, names, key)?;
+                        return self.encoders[key].load(value.as_any(), instance_path, ctx);
+                    }
+                    let (property, parents) = names_rs
+                        .split_last()
+                        .expect("Discriminator path is not empty");
+                    return Err(with_nested_path(instance_path, parents, |path| {
+                        missing_required_property(property, path)
+                    }));
+                }
+            };
+            self.get_variant_encoder(&key, instance_path, names_rs)?
+                .load(value, instance_path, ctx)
+        } else {
+            invalid_type!("dict", value, instance_path)
+        }
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match &self.load_discriminator {
+            DiscriminatorSource::Field(_, names_rs) if value.is_object() => {
+                match get_json_key(value, names_rs) {
+                    Some(key) => {
+                        if let Some(encoder) = DiscriminatorKey::from_json(key)
+                            .and_then(|key| self.encoders.get(&key))
+                            .or(self.fallback.as_ref())
+                        {
+                            return encoder.load_json(py, value, instance_path, ctx);
+                        }
+                    }
+                    None => {
+                        if let Some(key) = &self.default_key {
+                            let mut value = value.clone();
+                            insert_json_key(&mut value, names_rs, key);
+                            return self.encoders[key].load_json(py, &value, instance_path, ctx);
+                        }
+                    }
+                }
+            }
+            DiscriminatorSource::Callable(func) => {
+                // The function gets python values, the variant still loads the JSON value
+                return self
+                    .get_callable_load_encoder(func, &json_to_py(py, value)?, instance_path)?
+                    .load_json(py, value, instance_path, ctx);
+            }
+            _ => {}
+        }
+        // Invalid values are reported by `load`
+        self.load(&json_to_py(py, value)?, instance_path, ctx)
+    }
+}
+
+impl DiscriminatedUnionEncoder {
+    #[inline]
+    fn key_reprs(&self) -> Vec<String> {
+        self.keys.iter().map(DiscriminatorKey::repr).collect()
+    }
+
+    /// Returns the variant encoder of the discriminator value or the fallback one for unknown values.
+    /// `key_path` is the path of the discriminator within the value.
+    #[inline]
+    fn get_variant_encoder(
+        &self,
+        key: &Bound<'_, PyAny>,
+        instance_path: &InstancePath,
+        key_path: &[String],
+    ) -> PyResult<&TEncoder> {
+        match (DiscriminatorKey::try_from(key), &self.fallback) {
+            (Ok(key), fallback) => match (self.encoders.get(&key), fallback) {
+                (Some(encoder), _) | (None, Some(encoder)) => Ok(encoder.as_ref()),
+                (None, None) => Err(with_nested_path(instance_path, key_path, |path| {
+                    no_encoder_for_discriminator(&key.repr(), &self.key_reprs(), path)
+                })),
+            },
+            (Err(_), Some(fallback)) => Ok(fallback.as_ref()),
+            (Err(_), None) => Err(with_nested_path(instance_path, key_path, |path| {
+                no_encoder_for_discriminator(&fmt_py(key), &self.key_reprs(), path)
+            })),
+        }
+    }
+
+    /// Errors raised by the function are reported as validation errors,
+    /// `None` returned by it means that the value has no discriminator.
+    #[inline]
+    fn get_callable_load_encoder(
+        &self,
+        func: &Py<PyAny>,
+        value: &Bound<'_, PyAny>,
+        instance_path: &InstancePath,
+    ) -> PyResult<&TEncoder> {
+        let key = func
+            .bind(value.py())
+            .call1((value,))
+            .map_err(|err| map_py_err_to_schema_validation_error(value.py(), err, instance_path))?;
+        match &self.default_key {
+            Some(default_key) if key.is_none() => Ok(self.encoders[default_key].as_ref()),
+            _ => self.get_variant_encoder(&key, instance_path, &[]),
+        }
+    }
+
+    #[inline]
+    fn get_dump_encoder(&self, value: &Bound<'_, PyAny>) -> PyResult<&TEncoder> {
+        let key = match &self.dump_discriminator {
+            DiscriminatorSource::Field(names, names_rs) => {
+                let mut key = value.clone();
+                for property in names {
+                    let item = match key.downcast::<PyDict>() {
+                        Ok(dict) => dict.get_item(property)?,
+                        Err(_) => key.getattr(property).ok(),
+                    };
+                    match (item, &self.fallback) {
+                        (Some(item), _) => key = item,
+                        (None, Some(fallback)) => return Ok(fallback.as_ref()),
+                        (None, None) => {
+                            return Err(missing_required_property(
+                                &names_rs.join("."),
+                                &InstancePath::new(),
+                            ));
+                        }
+                    }
+                }
+                key
+            }
+            DiscriminatorSource::Callable(func) => func.bind(value.py()).call1((value,))?,
+        };
+        self.get_variant_encoder(&key, &InstancePath::new(), &[])
+    }
+}
+
+/// Returns `None` if the discriminator or any of its parents is missing.
+#[inline]
+fn get_load_key<'py>(
+    value: &Bound<'py, PyDict>,
+    names: &[Py<PyString>],
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let mut current = value.clone().into_any();
+    for property in names {
+        let Ok(dict) = current.downcast::<PyDict>() else {
+            return Ok(None);
+        };
+        match dict.get_item(property)? {
+            Some(item) => current = item,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+#[inline]
+fn get_json_key<'a>(value: &'a JsonValue, names: &[String]) -> Option<&'a JsonValue> {
+    let mut current = value;
+    for property in names {
+        current = current.as_object()?.get(property)?;
+    }
+    Some(current)
+}
+
+/// Returns a copy of the value with the discriminator set, nested dicts on the path are copied too.
+fn with_default_key<'py>(
+    value: &Bound<'py, PyDict>,
+    names: &[Py<PyString>],
+    key: &DiscriminatorKey,
+) -> PyResult<Bound<'py, PyDict>> {
+    let result = value.copy()?;
+    let (property, parents) = names.split_last().expect("Discriminator path is not empty");
+    let mut current = result.clone();
+    for parent in parents {
+        let nested = match current.get_item(parent)? {
+            Some(item) => match item.downcast::<PyDict>() {
+                Ok(item) => item.copy()?,
+                // Invalid values are reported by the variant
+                Err(_) => return Ok(result),
+            },
+            None => PyDict::new_bound(value.py()),
+        };
+        current.set_item(parent, &nested)?;
+        current = nested;
+    }
+    current.set_item(property, key)?;
+    Ok(result)
+}
+
+/// Calls `f` with the path of the nested `properties`.
+fn with_nested_path<R>(
+    instance_path: &InstancePath,
+    properties: &[String],
+    f: impl FnOnce(&InstancePath) -> R,
+) -> R {
+    match properties.split_first() {
+        Some((property, rest)) => with_nested_path(&instance_path.push(property.as_str()), rest, f),
+        None => f(instance_path),
+    }
+}
+
+/// Sets the value by the path of nested objects, missing objects are created.
+fn insert_json_key(value: &mut JsonValue, path: &[String], key: &DiscriminatorKey) {
+    if let JsonValue::Object(map) = value {
+        match path {
+            [property] => {
+                map.insert(property.clone(), key.to_json());
+            }
+            [parent, rest @ ..] => {
+                let nested = map
+                    .entry(parent.clone())
+                    .or_insert_with(|| JsonValue::Object(Default::default()));
+                insert_json_key(nested, rest, key);
+            }
+            [] => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeEncoder {
+    pub(crate) format: Option<DateFormat>,
+}
+
+impl TimeEncoder {
+    #[inline]
+    fn dump_str(&self, value: &Bound<'_, PyAny>) -> PyResult<String> {
+        let py_time = value.downcast::<PyTime>()?;
+        match &self.format {
+            Some(format) => format.dump_time(py_time),
+            None => dump_time(py_time),
+        }
+    }
+}
+
+impl Encoder for TimeEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        let result = self.dump_str(value)?;
+        Ok(result.into_py(value.py()).into_bound(value.py()))
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        write_str(buf, &self.dump_str(value)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        _ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        if let Ok(val) = value.downcast::<PyString>() {
+            if let Some(format) = &self.format {
+                return match format.parse_time(value.py(), val.to_str()?)? {
+                    Some(result) => Ok(result.into_any()),
+                    None => Err(date_format_mismatch(val, format, instance_path)),
+                };
+            }
+            if let Ok(result) = parse_time(value.py(), val.to_str()?) {
+                return Ok(result.into_any());
+            }
+        }
+        invalid_type!("time", value, instance_path)
+    }
+}
+
+#[cold]
+fn date_format_mismatch(
+    value: &Bound<'_, PyString>,
+    format: &DateFormat,
+    instance_path: &InstancePath,
+) -> PyErr {
+    raise_error(
+        format!(
+            r#""{}" does not match format "{}""#,
+            value,
+            format.pattern()
+        ),
+        instance_path,
+    )
+    .unwrap_err()
+}
+
+/// Unix timestamp representation of datetimes.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampFormat {
+    /// Number of microseconds in the timestamp unit
+    pub(crate) unit_microseconds: i128,
+    pub(crate) as_float: bool,
+}
+
+impl TimestampFormat {
+    #[inline]
+    fn dump<'py>(&self, py: Python<'py>, microseconds: i128) -> Bound<'py, PyAny> {
+        let result = if self.as_float {
+            (microseconds as f64 / self.unit_microseconds as f64).into_py(py)
+        } else {
+            (microseconds.div_euclid(self.unit_microseconds) as i64).into_py(py)
+        };
+        result.into_bound(py)
+    }
+
+    /// Returns microseconds since the Unix epoch, `None` if the value is out of range.
+    #[inline]
+    fn load(
+        &self,
+        value: &Bound<'_, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Option<i128>> {
+        Ok(if value.is_instance_of::<PyBool>() {
+            invalid_type!("timestamp", value, instance_path)
+        } else if let Ok(val) = value.downcast::<PyLong>() {
+            val.extract::<i64>()
+                .ok()
+                .map(|val| i128::from(val) * self.unit_microseconds)
+        } else if let Ok(val) = value.downcast::<PyFloat>() {
+            float_to_microseconds(val.value(), self.unit_microseconds)
+        } else if let Some(val) = value
+            .downcast::<PyString>()
+            .ok()
+            .filter(|_| ctx.cast_from_string())
+        {
+            match val.to_str()?.parse::<f64>() {
+                Ok(val) => float_to_microseconds(val, self.unit_microseconds),
+                Err(_) => invalid_type!("timestamp", value, instance_path),
+            }
+        } else {
+            invalid_type!("timestamp", value, instance_path)
+        })
+    }
+}
+
+/// Handling of aware and naive datetimes on load and dump.
+#[derive(Debug, Clone)]
+pub enum TimezonePolicy {
+    RequireAware,
+    RequireNaive,
+    ConvertToUtc,
+    /// Naive datetimes are considered to be in the given `tzinfo`.
+    AssumeTimezone(Py<PyAny>),
+}
+
+impl TimezonePolicy {
+    /// Returns the datetime adjusted to the policy or the violated requirement.
+    fn apply<'a>(
+        &self,
+        value: &Bound<'a, PyDateTime>,
+    ) -> PyResult<Result<Bound<'a, PyDateTime>, &'static str>> {
+        Ok(match self {
+            TimezonePolicy::RequireAware if !is_aware(value)? => Err("an aware datetime"),
+            TimezonePolicy::RequireNaive if is_aware(value)? => Err("a naive datetime"),
+            TimezonePolicy::ConvertToUtc => Ok(datetime_to_utc(value)?),
+            TimezonePolicy::AssumeTimezone(tzinfo) if !is_aware(value)? => {
+                Ok(datetime_with_tzinfo(value, tzinfo.bind(value.py()))?)
+            }
+            _ => Ok(value.clone()),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DateTimeEncoder {
+    pub(crate) naive_datetime_to_utc: bool,
+    pub(crate) format: Option<DateFormat>,
+    pub(crate) timestamp: Option<TimestampFormat>,
+    pub(crate) tz_policy: Option<TimezonePolicy>,
+}
+
+impl DateTimeEncoder {
+    #[inline]
+    fn dump_policy<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyDateTime>> {
+        let value = value.downcast::<PyDateTime>()?;
+        match &self.tz_policy {
+            Some(tz_policy) => tz_policy.apply(value)?.map_err(|expected| {
+                PyValueError::new_err(format!("{} is not {}", fmt_py(value), expected))
+            }),
+            None => Ok(value.clone()),
+        }
+    }
+
+    #[inline]
+    fn load_datetime<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyDateTime>> {
+        if let Some(timestamp) = self.timestamp {
+            return self.load_timestamp(value, timestamp, instance_path, ctx);
+        }
+        if let Ok(val) = value.downcast::<PyString>() {
+            if let Some(format) = &self.format {
+                return match format.parse_datetime(value.py(), val.to_str()?)? {
+                    Some(result) => Ok(result),
+                    None => Err(date_format_mismatch(val, format, instance_path)),
+                };
+            }
+            if let Ok(result) = parse_datetime(value.py(), val.to_str()?) {
+                return Ok(result);
+            }
+        }
+        invalid_type!("datetime", value, instance_path)
+    }
+
+    #[inline]
+    fn dump_str(&self, value: &Bound<'_, PyDateTime>) -> PyResult<String> {
+        match &self.format {
+            Some(format) => format.dump_datetime(value, self.naive_datetime_to_utc),
+            None => dump_datetime(value, self.naive_datetime_to_utc),
+        }
+    }
+
+    #[inline]
+    fn load_timestamp<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        timestamp: TimestampFormat,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyDateTime>> {
+        if let Some(microseconds) = timestamp.load(value, instance_path, ctx)? {
+            if let Some(result) = datetime_from_timestamp(value.py(), microseconds)? {
+                return Ok(result);
+            }
+        }
+        raise_error(
+            format!("{} is out of the datetime range", fmt_py(value)),
+            instance_path,
+        )?;
+        unreachable!()
+    }
+}
+
+/// Returns `None` for non finite and huge values, they are reported as out of range.
+#[inline]
+fn float_to_microseconds(value: f64, unit_microseconds: i128) -> Option<i128> {
+    let microseconds = (value * unit_microseconds as f64).round();
+    (microseconds.is_finite() && microseconds.abs() < 1e20).then_some(microseconds as i128)
+}
+
+impl Encoder for DateTimeEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        let py_datetime = &self.dump_policy(value)?;
+        if let Some(timestamp) = self.timestamp {
+            return Ok(timestamp.dump(value.py(), datetime_to_timestamp(py_datetime)?));
+        }
+        let result = self.dump_str(py_datetime)?;
+        Ok(result.into_py(value.py()).into_bound(value.py()))
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        let py_datetime = &self.dump_policy(value)?;
+        if let Some(timestamp) = self.timestamp {
+            let result = timestamp.dump(value.py(), datetime_to_timestamp(py_datetime)?);
+            return write_py_value(&result, buf);
+        }
+        write_str(buf, &self.dump_str(py_datetime)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let result = self.load_datetime(value, instance_path, ctx)?;
+        match &self.tz_policy {
+            Some(tz_policy) => match tz_policy.apply(&result)? {
+                Ok(result) => Ok(result.into_any()),
+                Err(expected) => {
+                    raise_error(
+                        format!("{} is not {}", fmt_py(value), expected),
+                        instance_path,
+                    )?;
+                    unreachable!()
+                }
+            },
+            None => Ok(result.into_any()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DateEncoder {
+    pub(crate) format: Option<DateFormat>,
+    pub(crate) timestamp: Option<TimestampFormat>,
+}
+
+impl DateEncoder {
+    #[inline]
+    fn dump_str(&self, value: &Bound<'_, PyDate>) -> PyResult<String> {
+        match &self.format {
+            Some(format) => format.dump_date(value),
+            None => dump_date(value),
+        }
+    }
+
+    #[inline]
+    fn load_timestamp<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        timestamp: TimestampFormat,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        if let Some(microseconds) = timestamp.load(value, instance_path, ctx)? {
+            if let Some(result) = date_from_timestamp(value.py(), microseconds)? {
+                return Ok(result.into_any());
+            }
+        }
+        raise_error(
+            format!("{} is not a valid date timestamp", fmt_py(value)),
+            instance_path,
+        )?;
+        unreachable!()
+    }
+}
+
+impl Encoder for DateEncoder {
+    #[inline]
     fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
         let py_date = value.downcast::<PyDate>()?;
-        let result = dump_date(py_date)?;
+        if let Some(timestamp) = self.timestamp {
+            return Ok(timestamp.dump(value.py(), date_to_timestamp(py_date)));
+        }
+        let result = self.dump_str(py_date)?;
         Ok(result.into_py(value.py()).into_bound(value.py()))
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        let py_date = value.downcast::<PyDate>()?;
+        if let Some(timestamp) = self.timestamp {
+            return write_py_value(&timestamp.dump(value.py(), date_to_timestamp(py_date)), buf);
+        }
+        write_str(buf, &self.dump_str(py_date)?);
+        Ok(())
+    }
+
     #[inline]
     fn load<'a>(
         &self,
         value: &Bound<'a, PyAny>,
         instance_path: &InstancePath,
-        _ctx: &Context,
+        ctx: &Context,
     ) -> PyResult<Bound<'a, PyAny>> {
+        if let Some(timestamp) = self.timestamp {
+            return self.load_timestamp(value, timestamp, instance_path, ctx);
+        }
         if let Ok(val) = value.downcast::<PyString>() {
+            if let Some(format) = &self.format {
+                return match format.parse_date(value.py(), val.to_str()?)? {
+                    Some(result) => Ok(result.into_any()),
+                    None => Err(date_format_mismatch(val, format, instance_path)),
+                };
+            }
             if let Ok(result) = parse_date(value.py(), val.to_str()?) {
                 return Ok(result.into_any());
             }
@@ -952,6 +2749,108 @@ impl Encoder for DateEncoder {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum TimeDeltaFormat {
+    /// ISO 8601 duration string (`P1DT2H`)
+    Iso,
+    /// Total seconds as float
+    Seconds,
+    /// Total seconds as int, the fractional part is truncated
+    IntSeconds,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimeDeltaEncoder {
+    pub(crate) format: TimeDeltaFormat,
+}
+
+impl TimeDeltaEncoder {
+    #[inline]
+    fn load_microseconds<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        microseconds: Option<i128>,
+        instance_path: &InstancePath,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        if let Some(microseconds) = microseconds {
+            if let Some(result) = timedelta_from_microseconds(value.py(), microseconds)? {
+                return Ok(result.into_any());
+            }
+        }
+        raise_error(
+            format!("{} is out of the duration range", fmt_py(value)),
+            instance_path,
+        )?;
+        unreachable!()
+    }
+
+    #[inline]
+    fn load_seconds<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        seconds: f64,
+        instance_path: &InstancePath,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let microseconds = float_to_microseconds(seconds, 1_000_000);
+        self.load_microseconds(value, microseconds, instance_path)
+    }
+}
+
+impl Encoder for TimeDeltaEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        let py_delta = value.downcast::<PyDelta>()?;
+        let py = value.py();
+        let result = match self.format {
+            TimeDeltaFormat::Iso => dump_timedelta(py_delta).into_py(py),
+            TimeDeltaFormat::Seconds => {
+                (timedelta_to_microseconds(py_delta) as f64 / 1e6).into_py(py)
+            }
+            TimeDeltaFormat::IntSeconds => {
+                ((timedelta_to_microseconds(py_delta) / 1_000_000) as i64).into_py(py)
+            }
+        };
+        Ok(result.into_bound(py))
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        if let Ok(val) = value.downcast::<PyString>() {
+            let val = val.to_str()?;
+            let err = match parse_timedelta(value.py(), val) {
+                Ok(result) => return Ok(result.into_any()),
+                Err(err) => err,
+            };
+            if ctx.cast_from_string() {
+                if let Ok(seconds) = val.parse::<f64>() {
+                    return self.load_seconds(value, seconds, instance_path);
+                }
+            }
+            if !err.is_instance_of::<ValidationError>(value.py()) {
+                return Err(err);
+            }
+            raise_error(err.value_bound(value.py()).to_string(), instance_path)?;
+            unreachable!()
+        } else if value.is_instance_of::<PyBool>() {
+            // bool is a subclass of int
+        } else if let Ok(val) = value.downcast::<PyLong>() {
+            let microseconds = val
+                .extract::<i64>()
+                .ok()
+                .map(|seconds| i128::from(seconds) * 1_000_000);
+            return self.load_microseconds(value, microseconds, instance_path);
+        } else if let Ok(val) = value.downcast::<PyFloat>() {
+            return self.load_seconds(value, val.value(), instance_path);
+        }
+        invalid_type!("duration", value, instance_path)
+    }
+}
+
 #[derive(Debug)]
 pub enum Encoders {
     Entity(EntityEncoder),
@@ -977,6 +2876,19 @@ impl Encoder for LazyEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        match self.inner.borrow().as_ref() {
+            Some(encoder) => match encoder {
+                Encoders::Entity(encoder) => encoder.dump_json(value, buf),
+                Encoders::TypedDict(encoder) => encoder.dump_json(value, buf),
+            },
+            None => Err(PyRuntimeError::new_err(
+                "[RUST] Invalid recursive encoder".to_string(),
+            )),
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -994,6 +2906,25 @@ impl Encoder for LazyEncoder {
             )),
         }
     }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.inner.borrow().as_ref() {
+            Some(encoder) => match encoder {
+                Encoders::Entity(encoder) => encoder.load_json(py, value, instance_path, ctx),
+                Encoders::TypedDict(encoder) => encoder.load_json(py, value, instance_path, ctx),
+            },
+            None => Err(PyRuntimeError::new_err(
+                "[RUST] Invalid recursive encoder".to_string(),
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1012,6 +2943,14 @@ impl Encoder for CustomEncoder {
         }
     }
 
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        match self.dump {
+            Some(ref dump) => write_py_value(&dump.bind(value.py()).call1((value,))?, buf),
+            None => self.inner.dump_json(value, buf),
+        }
+    }
+
     #[inline]
     fn load<'a>(
         &self,
@@ -1027,11 +2966,189 @@ impl Encoder for CustomEncoder {
         }
     }
 
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.load {
+            Some(_) => self.load(&json_to_py(py, value)?, instance_path, ctx),
+            None => self.inner.load_json(py, value, instance_path, ctx),
+        }
+    }
+
+    fn is_sequence(&self) -> bool {
+        self.inner.is_sequence()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatorEncoder {
+    pub(crate) inner: Box<TEncoder>,
+    pub(crate) validators: Vec<Py<PyAny>>,
+}
+
+/// Runs the user defined validators on the loaded value.
+/// `ValueError`s are reported as validation errors, other exceptions are propagated as is.
+#[inline]
+fn run_validators<'py>(
+    validators: &[Py<PyAny>],
+    value: Bound<'py, PyAny>,
+    instance_path: &InstancePath,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    for validator in validators {
+        if let Err(err) = validator.bind(py).call1((&value,)) {
+            if err.is_instance_of::<PyValueError>(py) && !err.is_instance_of::<ValidationError>(py)
+            {
+                return Err(map_py_err_to_schema_validation_error(
+                    py,
+                    err,
+                    instance_path,
+                ));
+            }
+            return Err(err);
+        }
+    }
+    Ok(value)
+}
+
+impl Encoder for ValidatorEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        self.inner.dump(value)
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        self.inner.dump_json(value, buf)
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let value = self.inner.load(value, instance_path, ctx)?;
+        if ctx.check_only {
+            return Ok(value);
+        }
+        run_validators(&self.validators, value, instance_path)
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let value = self.inner.load_json(py, value, instance_path, ctx)?;
+        run_validators(&self.validators, value, instance_path)
+    }
+
+    fn as_container_encoder(&self) -> Option<&dyn ContainerEncoder> {
+        self.inner
+            .as_container_encoder()
+            .map(|_| self as &dyn ContainerEncoder)
+    }
+
+    fn is_sequence(&self) -> bool {
+        self.inner.is_sequence()
+    }
+}
+
+impl ContainerEncoder for ValidatorEncoder {
+    fn get_fields(&self) -> QueryFields<'_> {
+        self.inner
+            .as_container_encoder()
+            .expect("checked in as_container_encoder")
+            .get_fields()
+    }
+}
+
+/// Loads the inner value with the given coercion mode, it applies to all nested values.
+#[derive(Debug, Clone)]
+pub struct CoercionEncoder {
+    pub(crate) inner: Box<TEncoder>,
+    pub(crate) mode: CoercionMode,
+}
+
+impl CoercionEncoder {
+    /// The exact pass of smart unions is kept, it is not overridden by the nested values modes.
+    #[inline]
+    fn context(&self, ctx: &Context) -> Context {
+        Context {
+            mode: if ctx.is_exact() {
+                CoercionMode::Exact
+            } else {
+                self.mode
+            },
+            ..ctx.clone()
+        }
+    }
+}
+
+impl Encoder for CoercionEncoder {
+    #[inline]
+    fn dump<'a>(&self, value: &Bound<'a, PyAny>) -> PyResult<Bound<'a, PyAny>> {
+        self.inner.dump(value)
+    }
+
+    #[inline]
+    fn dump_json(&self, value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+        self.inner.dump_json(value, buf)
+    }
+
+    #[inline]
+    fn load<'a>(
+        &self,
+        value: &Bound<'a, PyAny>,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let ctx = self.context(ctx);
+        self.inner.load(value, instance_path, &ctx)
+    }
+
+    #[inline]
+    fn load_json<'py>(
+        &self,
+        py: Python<'py>,
+        value: &JsonValue,
+        instance_path: &InstancePath,
+        ctx: &Context,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let ctx = self.context(ctx);
+        self.inner.load_json(py, value, instance_path, &ctx)
+    }
+
+    fn as_container_encoder(&self) -> Option<&dyn ContainerEncoder> {
+        self.inner
+            .as_container_encoder()
+            .map(|_| self as &dyn ContainerEncoder)
+    }
+
     fn is_sequence(&self) -> bool {
         self.inner.is_sequence()
     }
 }
 
+impl ContainerEncoder for CoercionEncoder {
+    fn get_fields(&self) -> QueryFields<'_> {
+        self.inner
+            .as_container_encoder()
+            .expect("checked in as_container_encoder")
+            .get_fields()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CustomTypeEncoder {
     pub(crate) dump: Py<PyAny>,
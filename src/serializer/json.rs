@@ -0,0 +1,171 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use serde_json::{Number, Value};
+
+use crate::errors::{ToPyErr, ValidationError};
+use crate::python::{create_py_list, py_list_get_item, py_list_set_item};
+
+pub(crate) type JsonValue = Value;
+
+#[inline]
+pub(crate) fn write_str(buf: &mut Vec<u8>, value: &str) {
+    serde_json::to_writer(buf, value).expect("writing to Vec<u8> never fails");
+}
+
+#[inline]
+pub(crate) fn write_raw(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(value.as_bytes());
+}
+
+#[inline]
+fn write_float(buf: &mut Vec<u8>, value: f64) {
+    // NaN and infinity are written as null, the same way orjson does.
+    serde_json::to_writer(buf, &value).expect("writing to Vec<u8> never fails");
+}
+
+#[inline]
+fn write_int(buf: &mut Vec<u8>, value: &Bound<'_, PyLong>) -> PyResult<()> {
+    match value.extract::<i64>() {
+        Ok(val) => serde_json::to_writer(buf, &val).expect("writing to Vec<u8> never fails"),
+        // Python ints are unbounded, fallback to their decimal representation
+        Err(_) => write_raw(buf, value.str()?.to_str()?),
+    };
+    Ok(())
+}
+
+/// Writes an already dumped python object (the result of `Encoder::dump`) as JSON.
+pub(crate) fn write_py_value(value: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+    if let Ok(val) = value.downcast::<PyString>() {
+        write_str(buf, val.to_str()?);
+    } else if value.is_none() {
+        write_raw(buf, "null");
+    } else if let Ok(val) = value.downcast::<PyBool>() {
+        write_raw(buf, if val.is_true() { "true" } else { "false" });
+    } else if let Ok(val) = value.downcast::<PyLong>() {
+        write_int(buf, val)?;
+    } else if let Ok(val) = value.downcast::<PyFloat>() {
+        write_float(buf, val.value());
+    } else if let Ok(val) = value.downcast::<PyDict>() {
+        buf.push(b'{');
+        for (index, (k, v)) in val.iter().enumerate() {
+            if index > 0 {
+                buf.push(b',');
+            }
+            write_py_key(&k, buf)?;
+            buf.push(b':');
+            write_py_value(&v, buf)?;
+        }
+        buf.push(b'}');
+    } else if let Ok(val) = value.downcast::<PyList>() {
+        buf.push(b'[');
+        for index in 0..val.len() {
+            if index > 0 {
+                buf.push(b',');
+            }
+            write_py_value(&py_list_get_item(val, index), buf)?;
+        }
+        buf.push(b']');
+    } else if let Ok(val) = value.downcast::<PyTuple>() {
+        buf.push(b'[');
+        for (index, item) in val.iter().enumerate() {
+            if index > 0 {
+                buf.push(b',');
+            }
+            write_py_value(&item, buf)?;
+        }
+        buf.push(b']');
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Object of type {} is not JSON serializable",
+            value.get_type().qualname()?
+        )));
+    }
+    Ok(())
+}
+
+/// Writes a dumped dictionary key, keys are coerced to strings like `json.dumps` does.
+pub(crate) fn write_py_key(key: &Bound<'_, PyAny>, buf: &mut Vec<u8>) -> PyResult<()> {
+    if let Ok(val) = key.downcast::<PyString>() {
+        write_str(buf, val.to_str()?);
+    } else if key.is_none() {
+        write_raw(buf, r#""null""#);
+    } else if let Ok(val) = key.downcast::<PyBool>() {
+        write_raw(
+            buf,
+            if val.is_true() {
+                r#""true""#
+            } else {
+                r#""false""#
+            },
+        );
+    } else if let Ok(val) = key.downcast::<PyLong>() {
+        buf.push(b'"');
+        write_int(buf, val)?;
+        buf.push(b'"');
+    } else if let Ok(val) = key.downcast::<PyFloat>() {
+        buf.push(b'"');
+        write_float(buf, val.value());
+        buf.push(b'"');
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "keys must be str, int, float, bool or None, not {}",
+            key.get_type().qualname()?
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_json(data: &Bound<'_, PyAny>) -> PyResult<JsonValue> {
+    let bytes = if let Ok(val) = data.downcast::<PyBytes>() {
+        val.as_bytes()
+    } else if let Ok(val) = data.downcast::<PyString>() {
+        val.to_str()?.as_bytes()
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected bytes or str, got {}",
+            data.get_type().qualname()?
+        )));
+    };
+    serde_json::from_slice(bytes)
+        .map_err(|e| ValidationError::new_err(format!("Invalid JSON: {}", e)))
+}
+
+/// Converts a parsed JSON value to the python object `json.loads` would return.
+pub(crate) fn json_to_py<'py>(py: Python<'py>, value: &JsonValue) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(val) => PyBool::new_bound(py, *val).to_owned().into_any(),
+        Value::Number(val) => number_to_py(py, val)?,
+        Value::String(val) => PyString::new_bound(py, val).into_any(),
+        Value::Array(items) => {
+            let result = create_py_list(py, items.len());
+            for (index, item) in items.iter().enumerate() {
+                py_list_set_item(&result, index, json_to_py(py, item)?);
+            }
+            result.into_any()
+        }
+        Value::Object(map) => {
+            let result = PyDict::new_bound(py);
+            for (k, v) in map {
+                result.set_item(k, json_to_py(py, v)?)?;
+            }
+            result.into_any()
+        }
+    })
+}
+
+#[inline]
+fn number_to_py<'py>(py: Python<'py>, value: &Number) -> PyResult<Bound<'py, PyAny>> {
+    let raw = value.as_str();
+    if raw.bytes().any(|c| matches!(c, b'.' | b'e' | b'E')) {
+        // Out of range values become infinity, the same way as in `json.loads`
+        let val: f64 = raw.parse().expect("JSON number is a valid float");
+        Ok(val.to_object(py).into_bound(py))
+    } else if let Some(val) = value.as_i64() {
+        Ok(val.to_object(py).into_bound(py))
+    } else {
+        // Integer out of the i64 range, python ints are unbounded
+        py.get_type_bound::<PyLong>().call1((raw,))
+    }
+}
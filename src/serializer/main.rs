@@ -1,28 +1,40 @@
+// pyo3 0.22 `#[pymethods]` wrappers convert `PyErr` into itself, the wrappers are generated
+// outside of the impl block so the lint can only be silenced per module
+#![allow(clippy::useless_conversion)]
+
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
-use pyo3::exceptions::{PyKeyError, PyRuntimeError};
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{
+    PyBool, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyList, PyLong,
+    PyMapping, PySet, PyString, PyTime, PyTuple, PyType,
+};
 use pyo3::{intern, PyAny, PyResult};
+use regex::Regex;
 
-use crate::python::{get_object_type, Type};
+use crate::python::{get_object_type, DateFormat, Type};
 use crate::serializer::encoders::{
-    BooleanEncoder, BytesEncoder, CustomTypeEncoder, DiscriminatorKey, FloatEncoder, IntEncoder,
-    QueryFields, StringEncoder, TypedDictEncoder, UnionEncoder,
+    BooleanEncoder, BytesEncoder, CoercionEncoder, CustomTypeEncoder, DiscriminatorKey,
+    FloatEncoder, IntEncoder, QueryFields, StringEncoder, TypedDictEncoder, UnionEncoder,
 };
+use crate::validator::formats::StringFormat;
 use crate::validator::types::{BaseType, EntityField};
-use crate::validator::{types, Context, InstancePath};
+use crate::validator::{types, CoercionMode, Context, InstancePath};
 
 use super::encoders::{
     ArrayEncoder, DecimalEncoder, DictionaryEncoder, EntityEncoder, EnumEncoder, Field,
-    NoopEncoder, OptionalEncoder, TupleEncoder, UUIDEncoder,
+    NoopEncoder, OptionalEncoder, SetEncoder, TupleEncoder, UUIDEncoder, ValidatorEncoder,
+    VarTupleEncoder,
 };
 use super::encoders::{
-    CustomEncoder, DateEncoder, DateTimeEncoder, DiscriminatedUnionEncoder, Encoders, LazyEncoder,
-    TEncoder, TimeEncoder,
+    CustomEncoder, DateEncoder, DateTimeEncoder, DiscriminatedUnionEncoder, DiscriminatorSource,
+    Encoders, LazyEncoder, TEncoder, TimeDeltaEncoder, TimeDeltaFormat, TimeEncoder,
+    TimestampFormat, TimezonePolicy,
 };
+use super::json::parse_json;
 
 type EncoderStateValue = Arc<AtomicRefCell<Option<Encoders>>>;
 
@@ -30,12 +42,18 @@ type EncoderStateValue = Arc<AtomicRefCell<Option<Encoders>>>;
 #[derive(Debug)]
 pub struct Serializer {
     pub encoder: Box<TEncoder>,
+    pub collect_errors: bool,
 }
 
 #[pymethods]
 impl Serializer {
     #[new]
-    fn new(type_info: &Bound<'_, PyAny>, naive_datetime_to_utc: bool) -> PyResult<Self> {
+    #[pyo3(signature = (type_info, naive_datetime_to_utc, collect_errors=false))]
+    fn new(
+        type_info: &Bound<'_, PyAny>,
+        naive_datetime_to_utc: bool,
+        collect_errors: bool,
+    ) -> PyResult<Self> {
         let obj_type = get_object_type(type_info)?;
         let mut encoder_state: HashMap<usize, EncoderStateValue> = HashMap::new();
 
@@ -46,6 +64,7 @@ impl Serializer {
                 &mut encoder_state,
                 naive_datetime_to_utc,
             )?,
+            collect_errors,
         };
         Ok(serializer)
     }
@@ -55,20 +74,36 @@ impl Serializer {
         self.encoder.dump(value)
     }
 
+    #[inline]
+    pub fn dump_json<'py>(&'py self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyBytes>> {
+        let mut buf = Vec::with_capacity(256);
+        self.encoder.dump_json(value, &mut buf)?;
+        Ok(PyBytes::new_bound(value.py(), &buf))
+    }
+
     #[inline]
     pub fn load<'py>(&'py self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
         let instance_path = InstancePath::new();
-        let ctx = Context::new(false);
+        let ctx = Context::new(false, self.collect_errors);
         self.encoder.load(value, &instance_path, &ctx)
     }
 
+    #[inline]
+    pub fn load_json<'py>(&'py self, data: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let value = parse_json(data)?;
+        let instance_path = InstancePath::new();
+        let ctx = Context::new(false, self.collect_errors);
+        self.encoder
+            .load_json(data.py(), &value, &instance_path, &ctx)
+    }
+
     #[inline]
     pub fn load_query_params<'py>(
         &'py self,
         data: &Bound<'py, PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let instance_path = InstancePath::new();
-        let ctx = Context::new(true);
+        let ctx = Context::new(true, self.collect_errors);
         let py = data.py();
 
         let encoder = if let Some(encoder) = self.encoder.as_container_encoder() {
@@ -83,9 +118,9 @@ impl Serializer {
         let fields = encoder.get_fields();
 
         let new_data = match fields {
-            QueryFields::Object(fields) => {
+            QueryFields::Object(fields, extra_is_sequence) => {
                 let new_data = PyDict::new_bound(py);
-                for field in fields {
+                for field in &fields {
                     let field_value = if field.is_sequence {
                         data.call_method1(intern!(py, "getall"), (field.name,))
                     } else {
@@ -98,6 +133,23 @@ impl Serializer {
                         Err(e) => return Err(e),
                     }
                 }
+                // Unknown keys are reported by `ForbidExtra` or collected by the extra fields.
+                for key in data.keys()?.iter()? {
+                    let key = key?;
+                    let mut is_field = false;
+                    for field in &fields {
+                        is_field |= key.eq(field.name)?;
+                    }
+                    if is_field {
+                        continue;
+                    }
+                    let field_value = if extra_is_sequence {
+                        data.call_method1(intern!(py, "getall"), (&key,))?
+                    } else {
+                        data.get_item(&key)?
+                    };
+                    new_data.set_item(&key, field_value)?;
+                }
                 new_data.into_any()
             }
             QueryFields::Dict(true) => {
@@ -135,7 +187,23 @@ pub fn get_encoder(
         }
         Type::String(type_info, base_type) => {
             let type_info = type_info.get().clone();
-            let encoder = StringEncoder { type_info };
+            let pattern = type_info
+                .pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|e| PyValueError::new_err(format!("Invalid pattern: {}", e)))?;
+            let format = type_info
+                .format
+                .as_deref()
+                .map(str::parse::<StringFormat>)
+                .transpose()
+                .map_err(PyValueError::new_err)?;
+            let encoder = StringEncoder {
+                type_info,
+                pattern,
+                format,
+            };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
         Type::Float(type_info, base_type) => {
@@ -147,9 +215,33 @@ pub fn get_encoder(
             let type_info = type_info.get().clone();
             let decimal_module = PyModule::import_bound(py, "decimal")?;
             let decimal_cls = decimal_module.getattr("Decimal")?;
+            let to_decimal = |value: &Option<Py<PyAny>>| -> PyResult<Option<Py<PyAny>>> {
+                value
+                    .as_ref()
+                    .map(|value| Ok(decimal_cls.call1((value.bind(py).str()?,))?.unbind()))
+                    .transpose()
+            };
+            let (min, max) = (to_decimal(&type_info.min)?, to_decimal(&type_info.max)?);
+            if let Some(rounding) = &type_info.rounding {
+                if !ROUNDING_MODES.contains(&rounding.as_str()) {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown rounding mode: {:?}",
+                        rounding
+                    )));
+                }
+            }
+            let quantum = match type_info.decimal_places {
+                Some(places) if type_info.rounding.is_some() => {
+                    Some(decimal_cls.call1((format!("1e-{}", places),))?.unbind())
+                }
+                _ => None,
+            };
             let encoder = DecimalEncoder {
                 type_info,
                 decimal_cls: decimal_cls.unbind(),
+                min,
+                max,
+                quantum,
             };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
@@ -166,18 +258,69 @@ pub fn get_encoder(
             };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
-        Type::Time(_, base_type) => {
-            let encoder = TimeEncoder {};
+        Type::Time(type_info, base_type) => {
+            let format = get_date_format(type_info.get().format.as_deref())?;
+            let encoder = TimeEncoder { format };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
-        Type::DateTime(_, base_type) => {
+        Type::DateTime(type_info, base_type) => {
+            let type_info = type_info.get();
+            let timestamp = get_timestamp_format(
+                type_info.timestamp_unit.as_deref(),
+                type_info.timestamp_as_float,
+            )?;
+            let tz_policy = match type_info.tz_policy.as_deref() {
+                None => None,
+                Some("require_aware") => Some(TimezonePolicy::RequireAware),
+                Some("require_naive") => Some(TimezonePolicy::RequireNaive),
+                Some("convert_to_utc") => Some(TimezonePolicy::ConvertToUtc),
+                Some("assume_timezone") => {
+                    let name = type_info.timezone.as_deref().unwrap_or_default();
+                    let zoneinfo = PyModule::import_bound(py, "zoneinfo")?;
+                    let tzinfo = zoneinfo.getattr("ZoneInfo")?.call1((name,)).map_err(|_| {
+                        PyValueError::new_err(format!("Unknown timezone: {:?}", name))
+                    })?;
+                    Some(TimezonePolicy::AssumeTimezone(tzinfo.unbind()))
+                }
+                Some(tz_policy) => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown timezone policy: {:?}",
+                        tz_policy
+                    )))
+                }
+            };
             let encoder = DateTimeEncoder {
                 naive_datetime_to_utc,
+                format: get_date_format(type_info.format.as_deref())?,
+                timestamp,
+                tz_policy,
             };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
-        Type::Date(_, base_type) => {
-            let encoder = DateEncoder {};
+        Type::Date(type_info, base_type) => {
+            let type_info = type_info.get();
+            let encoder = DateEncoder {
+                format: get_date_format(type_info.format.as_deref())?,
+                timestamp: get_timestamp_format(
+                    type_info.timestamp_unit.as_deref(),
+                    type_info.timestamp_as_float,
+                )?,
+            };
+            wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
+        }
+        Type::TimeDelta(type_info, base_type) => {
+            let format = match type_info.get().format.as_str() {
+                "iso" => TimeDeltaFormat::Iso,
+                "seconds" => TimeDeltaFormat::Seconds,
+                "int_seconds" => TimeDeltaFormat::IntSeconds,
+                format => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown timedelta format: {:?}",
+                        format
+                    )))
+                }
+            };
+            let encoder = TimeDeltaEncoder { format };
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
         Type::Bytes(_, base_type) => {
@@ -199,6 +342,44 @@ pub fn get_encoder(
             let encoder = get_encoder(py, inner, encoder_state, naive_datetime_to_utc)?;
             wrap_with_custom_encoder(py, base_type, Box::new(OptionalEncoder { encoder }))?
         }
+        Type::Validated(type_info, base_type) => {
+            let type_info = type_info.get();
+            let inner = get_object_type(type_info.inner.bind(py))?;
+            let encoder = get_encoder(py, inner, encoder_state, naive_datetime_to_utc)?;
+            let validators = type_info.validators.clone();
+            wrap_with_custom_encoder(
+                py,
+                base_type,
+                Box::new(ValidatorEncoder {
+                    inner: encoder,
+                    validators,
+                }),
+            )?
+        }
+        Type::Coercion(type_info, base_type) => {
+            let type_info = type_info.get();
+            let inner = get_object_type(type_info.inner.bind(py))?;
+            let encoder = get_encoder(py, inner, encoder_state, naive_datetime_to_utc)?;
+            let mode = match type_info.mode.as_str() {
+                "default" => CoercionMode::Default,
+                "strict" => CoercionMode::Strict,
+                "lax" => CoercionMode::Lax,
+                mode => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown coercion mode: {:?}",
+                        mode
+                    )))
+                }
+            };
+            wrap_with_custom_encoder(
+                py,
+                base_type,
+                Box::new(CoercionEncoder {
+                    inner: encoder,
+                    mode,
+                }),
+            )?
+        }
         Type::Dictionary(type_info, base_type) => {
             let key_type = get_object_type(type_info.get().key_type.bind(py))?;
             let value_type = get_object_type(type_info.get().value_type.bind(py))?;
@@ -244,12 +425,52 @@ pub fn get_encoder(
             }
             wrap_with_custom_encoder(py, base_type, Box::new(TupleEncoder { encoders }))?
         }
+        Type::VarTuple(type_info, base_type) => {
+            let type_info = type_info.get();
+            let item_type = get_object_type(type_info.item_type.bind(py))?;
+            let encoder = get_encoder(py, item_type, encoder_state, naive_datetime_to_utc)?;
+            wrap_with_custom_encoder(
+                py,
+                base_type,
+                Box::new(VarTupleEncoder {
+                    encoder,
+                    min_length: type_info.min_length,
+                    max_length: type_info.max_length,
+                }),
+            )?
+        }
+        Type::Set(type_info, base_type) => {
+            let type_info = type_info.get();
+            let item_type = get_object_type(type_info.item_type.bind(py))?;
+            let encoder = get_encoder(py, item_type, encoder_state, naive_datetime_to_utc)?;
+            wrap_with_custom_encoder(
+                py,
+                base_type,
+                Box::new(SetEncoder {
+                    encoder,
+                    frozen: type_info.frozen,
+                    forbid_duplicates: type_info.forbid_duplicates,
+                    sort_on_dump: type_info.sort_on_dump,
+                }),
+            )?
+        }
         Type::Union(type_info, base_type) => {
             let item_types = type_info.get().item_types.bind(py).downcast::<PyList>()?;
 
             let mut encoders = vec![];
-
-            for value in item_types.iter() {
+            let dump_encoders = PyDict::new_bound(py);
+            let ambiguous_types = PySet::empty_bound(py)?;
+
+            for (index, value) in item_types.iter().enumerate() {
+                for cls in get_dump_types(&value)?.unwrap_or_default() {
+                    match dump_encoders.get_item(&cls)? {
+                        Some(other) if other.extract::<usize>()? != index => {
+                            ambiguous_types.add(&cls)?
+                        }
+                        Some(_) => {}
+                        None => dump_encoders.set_item(&cls, index)?,
+                    }
+                }
                 let encoder = get_encoder(
                     py,
                     get_object_type(&value)?,
@@ -265,21 +486,22 @@ pub fn get_encoder(
                 Box::new(UnionEncoder {
                     encoders,
                     union_repr: type_info.get().union_repr.clone(),
+                    smart: type_info.get().smart,
+                    dump_encoders: {
+                        // Values of types claimed by several variants are dumped by trying all of them
+                        for cls in ambiguous_types.iter() {
+                            dump_encoders.del_item(cls)?;
+                        }
+                        dump_encoders.unbind()
+                    },
                 }),
             )?
         }
         Type::DiscriminatedUnion(type_info, base_type) => {
-            let dump_discriminator = type_info
-                .get()
-                .dump_discriminator
-                .bind(py)
-                .downcast::<PyString>()?;
-
-            let load_discriminator = type_info
-                .get()
-                .load_discriminator
-                .bind(py)
-                .downcast::<PyString>()?;
+            let dump_discriminator =
+                get_discriminator_source(type_info.get().dump_discriminator.bind(py))?;
+            let load_discriminator =
+                get_discriminator_source(type_info.get().load_discriminator.bind(py))?;
 
             let item_types = type_info.get().item_types.bind(py).downcast::<PyDict>()?;
 
@@ -287,9 +509,7 @@ pub fn get_encoder(
             let mut keys = vec![];
 
             for (key, value) in item_types.iter() {
-                let key = DiscriminatorKey::try_from(&key).map_err(|e| {
-                    PyRuntimeError::new_err(format!("Invalid key for DiscriminatedUnion: {:?}", e))
-                })?;
+                let key = get_discriminator_key(&key)?;
                 let encoder = get_encoder(
                     py,
                     get_object_type(&value)?,
@@ -300,21 +520,35 @@ pub fn get_encoder(
                 encoders.insert(key, encoder);
             }
 
+            let fallback = match &type_info.get().fallback {
+                Some(fallback) => Some(get_encoder(
+                    py,
+                    get_object_type(fallback.bind(py))?,
+                    encoder_state,
+                    naive_datetime_to_utc,
+                )?),
+                None => None,
+            };
+
             wrap_with_custom_encoder(
                 py,
                 base_type,
                 Box::new(DiscriminatedUnionEncoder {
                     encoders,
-                    dump_discriminator: dump_discriminator.clone().unbind(),
-                    load_discriminator: load_discriminator.clone().unbind(),
-                    load_discriminator_rs: load_discriminator.to_string_lossy().into(),
+                    dump_discriminator,
+                    load_discriminator,
                     keys,
+                    default_key: match &type_info.get().default_tag {
+                        Some(default_tag) => Some(get_discriminator_key(default_tag.bind(py))?),
+                        None => None,
+                    },
+                    fallback,
                 }),
             )?
         }
         Type::Entity(type_info, base_type, python_object_id) => {
             let type_info = type_info.get();
-            let fields =
+            let (fields, extra_fields) =
                 iterate_on_fields(py, &type_info.fields, encoder_state, naive_datetime_to_utc)?;
 
             let builtins = PyModule::import_bound(py, intern!(py, "builtins"))?;
@@ -326,6 +560,12 @@ pub fn get_encoder(
                 fields,
                 omit_none: type_info.omit_none,
                 is_frozen: type_info.is_frozen,
+                forbid_extra: type_info.forbid_extra,
+                call_post_init: type_info.call_post_init,
+                use_constructor: type_info.use_constructor,
+                post_init_args: type_info.post_init_args.clone_ref(py),
+                validators: type_info.validators.clone(),
+                extra_fields,
                 create_object: create_object.unbind(),
                 object_set_attr: object_set_attr.unbind(),
                 cls: type_info.cls.clone(),
@@ -336,7 +576,7 @@ pub fn get_encoder(
             wrap_with_custom_encoder(py, base_type, Box::new(encoder))?
         }
         Type::TypedDict(type_info, base_type, python_object_id) => {
-            let fields = iterate_on_fields(
+            let (fields, extra_fields) = iterate_on_fields(
                 py,
                 &type_info.get().fields,
                 encoder_state,
@@ -346,6 +586,8 @@ pub fn get_encoder(
             let encoder = TypedDictEncoder {
                 fields,
                 omit_none: type_info.get().omit_none,
+                forbid_extra: type_info.get().forbid_extra,
+                extra_fields,
             };
             let val = encoder_state.entry(python_object_id).or_default();
             AtomicRefCell::<Option<Encoders>>::borrow_mut(val)
@@ -422,13 +664,125 @@ fn wrap_with_custom_encoder(
     }
 }
 
+fn get_discriminator_key(key: &Bound<'_, PyAny>) -> PyResult<DiscriminatorKey> {
+    DiscriminatorKey::try_from(key).map_err(|_| {
+        PyRuntimeError::new_err(format!("Invalid key for DiscriminatedUnion: {}", key))
+    })
+}
+
+/// Discriminators are either dotted paths to the field or functions.
+fn get_discriminator_source(discriminator: &Bound<'_, PyAny>) -> PyResult<DiscriminatorSource> {
+    let py = discriminator.py();
+    match discriminator.downcast::<PyString>() {
+        Ok(path) => {
+            let names_rs: Vec<String> = path.to_str()?.split('.').map(String::from).collect();
+            let names = names_rs
+                .iter()
+                .map(|name| PyString::new_bound(py, name).unbind())
+                .collect();
+            Ok(DiscriminatorSource::Field(names, names_rs))
+        }
+        Err(_) => Ok(DiscriminatorSource::Callable(
+            discriminator.clone().unbind(),
+        )),
+    }
+}
+
+/// Returns the classes of values dumped by the type, `None` if the values can be of any class.
+fn get_dump_types<'py>(type_info: &Bound<'py, PyAny>) -> PyResult<Option<Vec<Bound<'py, PyAny>>>> {
+    let py = type_info.py();
+    let type_of = |cls: Bound<'py, PyType>| Ok(Some(vec![cls.into_any()]));
+    let base_type = type_info.extract::<Bound<'_, BaseType>>()?;
+    if let Some(custom_encoder) = &base_type.get().custom_encoder {
+        if custom_encoder
+            .extract::<types::CustomEncoder>(py)?
+            .serialize
+            .is_some()
+        {
+            return Ok(None);
+        }
+    }
+    match get_object_type(type_info)? {
+        Type::Integer(..) => type_of(py.get_type_bound::<PyLong>()),
+        Type::Float(..) => Ok(Some(vec![
+            py.get_type_bound::<PyFloat>().into_any(),
+            py.get_type_bound::<PyLong>().into_any(),
+        ])),
+        Type::Decimal(..) => Ok(Some(vec![
+            PyModule::import_bound(py, "decimal")?.getattr("Decimal")?
+        ])),
+        Type::String(..) => type_of(py.get_type_bound::<PyString>()),
+        Type::Boolean(..) => type_of(py.get_type_bound::<PyBool>()),
+        Type::Uuid(..) => Ok(Some(vec![
+            PyModule::import_bound(py, "uuid")?.getattr("UUID")?
+        ])),
+        Type::Bytes(..) => type_of(py.get_type_bound::<PyBytes>()),
+        Type::Time(..) => type_of(py.get_type_bound::<PyTime>()),
+        Type::DateTime(..) => type_of(py.get_type_bound::<PyDateTime>()),
+        Type::Date(..) => type_of(py.get_type_bound::<PyDate>()),
+        Type::TimeDelta(..) => type_of(py.get_type_bound::<PyDelta>()),
+        Type::Entity(type_info, ..) => Ok(Some(vec![type_info.get().cls.bind(py).clone()])),
+        Type::Enum(type_info, ..) => Ok(Some(vec![type_info.get().cls.bind(py).clone()])),
+        Type::TypedDict(..) | Type::Dictionary(..) => type_of(py.get_type_bound::<PyDict>()),
+        Type::Array(..) => type_of(py.get_type_bound::<PyList>()),
+        Type::Tuple(..) | Type::VarTuple(..) => type_of(py.get_type_bound::<PyTuple>()),
+        Type::Set(type_info, ..) if type_info.get().frozen => {
+            type_of(py.get_type_bound::<PyFrozenSet>())
+        }
+        Type::Set(..) => type_of(py.get_type_bound::<PySet>()),
+        Type::Literal(type_info, ..) => Ok(Some(
+            type_info
+                .get()
+                .args
+                .bind(py)
+                .iter()
+                .map(|arg| arg.get_type().into_any())
+                .collect(),
+        )),
+        Type::Optional(type_info, ..) => {
+            let inner = get_dump_types(type_info.get().inner.bind(py))?;
+            Ok(inner.map(|mut types| {
+                types.push(py.None().bind(py).get_type().into_any());
+                types
+            }))
+        }
+        Type::Validated(type_info, ..) => get_dump_types(type_info.get().inner.bind(py)),
+        Type::Coercion(type_info, ..) => get_dump_types(type_info.get().inner.bind(py)),
+        Type::Union(type_info, ..) => get_items_dump_types(type_info.get().item_types.bind(py)),
+        Type::DiscriminatedUnion(type_info, ..) => {
+            let item_types = type_info.get().item_types.bind(py).downcast::<PyDict>()?;
+            let item_types = item_types.values();
+            if let Some(fallback) = &type_info.get().fallback {
+                item_types.append(fallback)?;
+            }
+            get_items_dump_types(item_types.as_any())
+        }
+        Type::Any(..) | Type::RecursionHolder(..) | Type::Custom(..) => Ok(None),
+    }
+}
+
+fn get_items_dump_types<'py>(
+    items: &Bound<'py, PyAny>,
+) -> PyResult<Option<Vec<Bound<'py, PyAny>>>> {
+    let mut result = vec![];
+    for item in items.iter()? {
+        match get_dump_types(&item?)? {
+            Some(types) => result.extend(types),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(result))
+}
+
+/// Returns the regular fields and the field collecting extra keys, if any.
 fn iterate_on_fields(
     py: Python<'_>,
     entity_fields: &Vec<EntityField>,
     encoder_state: &mut HashMap<usize, EncoderStateValue>,
     naive_datetime_to_utc: bool,
-) -> PyResult<Vec<Field>> {
+) -> PyResult<(Vec<Field>, Option<Field>)> {
     let mut fields = vec![];
+    let mut extra_fields = None;
     for field in entity_fields {
         let f_name = field.name.downcast_bound::<PyString>(py)?;
         let dict_key = field.dict_key.downcast_bound::<PyString>(py)?;
@@ -440,10 +794,59 @@ fn iterate_on_fields(
             dict_key_rs: dict_key.to_string_lossy().into(),
             encoder: get_encoder(py, f_type, encoder_state, naive_datetime_to_utc)?,
             required: field.required,
+            read_only: field.read_only,
+            write_only: field.write_only,
+            init_arg: match (field.init, &field.init_alias) {
+                (false, _) => None,
+                (true, Some(alias)) => Some(alias.downcast_bound::<PyString>(py)?.clone().unbind()),
+                (true, None) => Some(f_name.clone().unbind()),
+            },
             default: field.default.clone().into(),
             default_factory: field.default_factory.clone().into(),
         };
-        fields.push(fld);
+        if field.is_extra_fields {
+            extra_fields = Some(fld);
+        } else {
+            fields.push(fld);
+        }
     }
-    Ok(fields)
+    Ok((fields, extra_fields))
+}
+
+/// Rounding modes of the `decimal` module.
+const ROUNDING_MODES: [&str; 8] = [
+    "ROUND_CEILING",
+    "ROUND_DOWN",
+    "ROUND_FLOOR",
+    "ROUND_HALF_DOWN",
+    "ROUND_HALF_EVEN",
+    "ROUND_HALF_UP",
+    "ROUND_UP",
+    "ROUND_05UP",
+];
+
+fn get_timestamp_format(unit: Option<&str>, as_float: bool) -> PyResult<Option<TimestampFormat>> {
+    let unit_microseconds = match unit {
+        None => return Ok(None),
+        Some("s") => 1_000_000,
+        Some("ms") => 1_000,
+        Some("us") => 1,
+        Some(unit) => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown timestamp unit: {:?}",
+                unit
+            )))
+        }
+    };
+    Ok(Some(TimestampFormat {
+        unit_microseconds,
+        as_float,
+    }))
+}
+
+fn get_date_format(format: Option<&str>) -> PyResult<Option<DateFormat>> {
+    format
+        .map(DateFormat::new)
+        .transpose()
+        .map_err(PyValueError::new_err)
 }
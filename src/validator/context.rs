@@ -1,16 +1,60 @@
 use pyo3::{Bound, PyAny};
 
+/// Which values of other types are coerced to the expected type on load.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CoercionMode {
+    /// Ints are accepted for floats, numbers and numeric strings for decimals.
+    Default,
+    /// Only values of the expected type: no bools for numbers, ints for floats or strings for decimals.
+    Strict,
+    /// Numeric and boolean strings, integral floats for ints, 0 and 1 for bools.
+    Lax,
+    /// Strict, and values must already be of the loaded type: no JSON integers for floats and
+    /// only `Decimal` instances for decimals. Used by smart unions to find the exact match.
+    Exact,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Context {
     pub try_cast_from_string: bool,
+    /// Keep validating after the first error and report all of them at once.
+    pub collect_errors: bool,
+    pub mode: CoercionMode,
+    /// Only check that the value loads, user code that may have side effects (constructors,
+    /// `__post_init__`, validators) is not run. Used by the exact pass of smart unions.
+    pub check_only: bool,
 }
 
 impl Context {
-    pub fn new(try_cast_from_string: bool) -> Self {
+    pub fn new(try_cast_from_string: bool, collect_errors: bool) -> Self {
         Context {
             try_cast_from_string,
+            collect_errors,
+            mode: CoercionMode::Default,
+            check_only: false,
         }
     }
+
+    /// Query params values are always strings, so they are parsed in any mode.
+    #[inline]
+    pub fn cast_from_string(&self) -> bool {
+        self.try_cast_from_string || self.mode == CoercionMode::Lax
+    }
+
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        matches!(self.mode, CoercionMode::Strict | CoercionMode::Exact)
+    }
+
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.mode == CoercionMode::Exact
+    }
+
+    #[inline]
+    pub fn is_lax(&self) -> bool {
+        self.mode == CoercionMode::Lax
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -45,7 +89,7 @@ impl<'a> InstancePath<'a> {
         }
     }
 
-    pub(crate) fn to_vec(&'a self) -> Vec<PathChunk> {
+    pub(crate) fn to_vec(&'a self) -> Vec<PathChunk<'a>> {
         // The path capacity should be the average depth so we avoid extra allocations
         let mut result = Vec::with_capacity(6);
         let mut current = self;
@@ -1,10 +1,11 @@
-use pyo3::types::PyType;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyType};
 use pyo3::{PyErr, PyResult, Python};
 
 use crate::errors::ErrorItem;
 use crate::errors::SchemaValidationError;
 use crate::validator::context::PathChunk;
-use crate::validator::InstancePath;
+use crate::validator::{Context, InstancePath};
 
 pub fn raise_error<T: Into<String>>(error: T, instance_path: &InstancePath) -> PyResult<()> {
     Python::with_gil(|py| {
@@ -19,13 +20,16 @@ pub fn raise_error<T: Into<String>>(error: T, instance_path: &InstancePath) -> P
 }
 
 fn into_err_item<T: Into<String>>(error: T, instance_path: &InstancePath) -> ErrorItem {
-    let instance_path = into_path(instance_path);
-    ErrorItem::new(error.into(), instance_path)
+    let (instance_path, depth) = into_path(instance_path);
+    ErrorItem::with_depth(error.into(), instance_path, depth)
 }
 
-fn into_path(pointer: &InstancePath) -> String {
+/// Returns the rendered path and its depth, `""` is the root.
+fn into_path(pointer: &InstancePath) -> (String, usize) {
+    let chunks = pointer.to_vec();
+    let depth = chunks.len();
     let mut path = vec![];
-    for chunk in pointer.to_vec() {
+    for chunk in chunks {
         match chunk {
             PathChunk::Property(property) => {
                 path.push(property.to_string());
@@ -34,7 +38,73 @@ fn into_path(pointer: &InstancePath) -> String {
             PathChunk::PropertyValue(value) => path.push(value.to_string()),
         };
     }
-    path.join("/")
+    (path.join("/"), depth)
+}
+
+/// Keeps the validation errors of the union variant that came closest to loading the value:
+/// the one that failed deepest inside the value, then the one with fewer errors.
+#[derive(Default)]
+pub struct UnionErrors {
+    best: Option<(usize, usize, Py<PyList>)>,
+}
+
+impl UnionErrors {
+    /// Errors other than `SchemaValidationError` don't tell how close the variant was, so they are skipped.
+    #[inline]
+    pub fn add(&mut self, py: Python<'_>, error: PyErr) -> PyResult<()> {
+        let Ok(error) = error
+            .value_bound(py)
+            .downcast::<SchemaValidationError>()
+            .cloned()
+        else {
+            return Ok(());
+        };
+        let errors = error.getattr("errors")?.downcast_into::<PyList>()?;
+        let mut depth = 0;
+        for item in errors.iter() {
+            if let Ok(item) = item.downcast::<ErrorItem>() {
+                depth = depth.max(item.borrow().depth);
+            }
+        }
+        let count = errors.len();
+        let is_better = match &self.best {
+            Some((best_depth, best_count, _)) => {
+                depth > *best_depth || (depth == *best_depth && count < *best_count)
+            }
+            None => true,
+        };
+        if is_better {
+            self.best = Some((depth, count, errors.unbind()));
+        }
+        Ok(())
+    }
+
+    /// Raises the `message` error, followed by the errors of the closest variant if it
+    /// failed inside the value rather than on the value itself.
+    pub fn raise(
+        self,
+        py: Python<'_>,
+        message: String,
+        instance_path: &InstancePath,
+    ) -> PyResult<()> {
+        let (path, depth) = into_path(instance_path);
+        let errors = PyList::new_bound(
+            py,
+            [Py::new(py, ErrorItem::with_depth(message, path, depth))?],
+        );
+        if let Some((best_depth, _, best)) = self.best {
+            if best_depth > depth {
+                for item in best.bind(py).iter() {
+                    errors.append(item)?;
+                }
+            }
+        }
+        let pyerror_type = PyType::new_bound::<SchemaValidationError>(py);
+        Err(PyErr::from_type_bound(
+            pyerror_type,
+            ("Schema validation failed".to_string(), errors.unbind()),
+        ))
+    }
 }
 
 pub fn map_py_err_to_schema_validation_error(
@@ -43,11 +113,64 @@ pub fn map_py_err_to_schema_validation_error(
     instance_path: &InstancePath,
 ) -> PyErr {
     let error_message = format!("{}", &error);
-    let instance_path = into_path(instance_path);
     let err = PyErr::new::<SchemaValidationError, _>((
         "Schema validation failed".to_string(),
-        vec![ErrorItem::new(error_message, instance_path)],
+        vec![into_err_item(error_message, instance_path)],
     ));
     err.set_cause(py, Some(error));
     err
 }
+
+/// Accumulates validation errors of container items when `Context::collect_errors` is enabled.
+/// Otherwise, the first error is returned as is.
+pub struct ErrorCollector {
+    enabled: bool,
+    errors: Vec<PyObject>,
+}
+
+impl ErrorCollector {
+    #[inline]
+    pub fn new(ctx: &Context) -> Self {
+        ErrorCollector {
+            enabled: ctx.collect_errors,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns `Ok(None)` if the validation error was collected.
+    #[inline]
+    pub fn collect<T>(&mut self, py: Python<'_>, result: PyResult<T>) -> PyResult<Option<T>> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if self.enabled => {
+                match err.value_bound(py).downcast::<SchemaValidationError>() {
+                    Ok(error) => {
+                        let errors = error.getattr("errors")?;
+                        for item in errors.downcast::<PyList>()?.iter() {
+                            self.errors.push(item.unbind());
+                        }
+                        Ok(None)
+                    }
+                    Err(_) => Err(err),
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Raises `SchemaValidationError` with all collected errors.
+    #[inline]
+    pub fn finish(self, py: Python<'_>) -> PyResult<()> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+        let pyerror_type = PyType::new_bound::<SchemaValidationError>(py);
+        Err(PyErr::from_type_bound(
+            pyerror_type,
+            (
+                "Schema validation failed".to_string(),
+                PyList::new_bound(py, self.errors).unbind(),
+            ),
+        ))
+    }
+}
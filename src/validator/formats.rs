@@ -0,0 +1,160 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use speedate::{Date, DateTime, Time};
+use uuid::Uuid;
+
+/// Semantic string formats (a subset of the JSON Schema `format` vocabulary) checked on load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringFormat {
+    Email,
+    Uri,
+    Hostname,
+    Ipv4,
+    Ipv6,
+    Uuid,
+    Date,
+    Time,
+    DateTime,
+}
+
+impl StringFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StringFormat::Email => "email",
+            StringFormat::Uri => "uri",
+            StringFormat::Hostname => "hostname",
+            StringFormat::Ipv4 => "ipv4",
+            StringFormat::Ipv6 => "ipv6",
+            StringFormat::Uuid => "uuid",
+            StringFormat::Date => "date",
+            StringFormat::Time => "time",
+            StringFormat::DateTime => "date-time",
+        }
+    }
+
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            StringFormat::Email => is_email(value),
+            StringFormat::Uri => is_uri(value),
+            StringFormat::Hostname => is_hostname(value),
+            StringFormat::Ipv4 => Ipv4Addr::from_str(value).is_ok(),
+            StringFormat::Ipv6 => Ipv6Addr::from_str(value).is_ok(),
+            StringFormat::Uuid => value.len() == 36 && Uuid::parse_str(value).is_ok(),
+            StringFormat::Date => Date::parse_str_rfc3339(value).is_ok(),
+            StringFormat::Time => Time::parse_str(value).is_ok(),
+            StringFormat::DateTime => DateTime::parse_str_rfc3339(value).is_ok(),
+        }
+    }
+}
+
+impl FromStr for StringFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "email" => Ok(StringFormat::Email),
+            "uri" => Ok(StringFormat::Uri),
+            "hostname" => Ok(StringFormat::Hostname),
+            "ipv4" => Ok(StringFormat::Ipv4),
+            "ipv6" => Ok(StringFormat::Ipv6),
+            "uuid" => Ok(StringFormat::Uuid),
+            "date" => Ok(StringFormat::Date),
+            "time" => Ok(StringFormat::Time),
+            "date-time" => Ok(StringFormat::DateTime),
+            _ => Err(format!("Unknown string format: {:?}", s)),
+        }
+    }
+}
+
+impl fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// RFC 1123 host name: dot separated labels of letters, digits and hyphens.
+fn is_hostname(value: &str) -> bool {
+    if value.is_empty() || value.len() > 253 {
+        return false;
+    }
+    value.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    })
+}
+
+/// RFC 5321 mailbox: a dot-atom or quoted local part and a host name or address literal.
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.rsplit_once('@') else {
+        return false;
+    };
+    is_email_local_part(local) && is_email_domain(domain)
+}
+
+fn is_email_local_part(local: &str) -> bool {
+    if local.is_empty() || local.len() > 64 {
+        return false;
+    }
+    if local.len() >= 2 && local.starts_with('"') && local.ends_with('"') {
+        let quoted = &local[1..local.len() - 1];
+        return quoted.bytes().all(|b| (b' '..=b'~').contains(&b));
+    }
+    local.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&b))
+    })
+}
+
+fn is_email_domain(domain: &str) -> bool {
+    match domain
+        .strip_prefix('[')
+        .and_then(|literal| literal.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix("IPv6:") {
+            Some(address) => Ipv6Addr::from_str(address).is_ok(),
+            None => Ipv4Addr::from_str(literal).is_ok(),
+        },
+        None => is_hostname(domain),
+    }
+}
+
+/// RFC 3986 absolute URI: a scheme followed by characters allowed in the rest of the URI.
+fn is_uri(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+    let mut scheme_bytes = scheme.bytes();
+    if !scheme_bytes.next().is_some_and(|b| b.is_ascii_alphabetic()) {
+        return false;
+    }
+    if !scheme_bytes.all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b)) {
+        return false;
+    }
+    let bytes = rest.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                if !(i + 2 < bytes.len()
+                    && bytes[i + 1].is_ascii_hexdigit()
+                    && bytes[i + 2].is_ascii_hexdigit())
+                {
+                    return false;
+                }
+                i += 3;
+            }
+            b if b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&b) => i += 1,
+            _ => return false,
+        }
+    }
+    true
+}
@@ -1,7 +1,8 @@
 mod context;
 mod errors;
+pub mod formats;
 pub mod types;
 pub mod validators;
 
-pub use context::{Context, InstancePath};
-pub use errors::{map_py_err_to_schema_validation_error, raise_error};
+pub use context::{CoercionMode, Context, InstancePath};
+pub use errors::{map_py_err_to_schema_validation_error, raise_error, ErrorCollector, UnionErrors};
@@ -1,9 +1,14 @@
+// pyo3 0.22 `#[pymethods]` wrappers convert `PyErr` into itself, the wrappers are generated
+// outside of the impl block so the lint can only be silenced per module
+#![allow(clippy::useless_conversion)]
+
+use num_bigint::BigInt;
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::intern;
 
 use crate::python::fmt_py;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyLong, PyNone};
+use pyo3::types::{PyDict, PyList, PyLong, PyNone, PyTuple};
 
 macro_rules! py_eq {
     ($obj1:expr, $obj2:expr, $py:expr) => {
@@ -76,9 +81,9 @@ impl CustomEncoder {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IntegerType {
     #[pyo3(get)]
-    pub min: Option<i64>,
+    pub min: Option<BigInt>,
     #[pyo3(get)]
-    pub max: Option<i64>,
+    pub max: Option<BigInt>,
 }
 
 #[pymethods]
@@ -86,8 +91,8 @@ impl IntegerType {
     #[new]
     #[pyo3(signature = (min=None, max=None, custom_encoder=None))]
     fn new(
-        min: Option<i64>,
-        max: Option<i64>,
+        min: Option<BigInt>,
+        max: Option<BigInt>,
         custom_encoder: Option<&Bound<'_, PyAny>>,
     ) -> (Self, BaseType) {
         (IntegerType { min, max }, BaseType::new(custom_encoder))
@@ -137,34 +142,81 @@ impl FloatType {
 }
 
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct DecimalType {
     #[pyo3(get)]
-    pub min: Option<f64>,
+    pub min: Option<Py<PyAny>>,
     #[pyo3(get)]
-    pub max: Option<f64>,
+    pub max: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub max_digits: Option<usize>,
+    #[pyo3(get)]
+    pub decimal_places: Option<usize>,
+    #[pyo3(get)]
+    pub rounding: Option<String>,
+    #[pyo3(get)]
+    pub dump_as_number: bool,
 }
 
 #[pymethods]
 impl DecimalType {
     #[new]
-    #[pyo3(signature = (min=None, max=None, custom_encoder=None))]
+    #[pyo3(signature = (
+        min=None,
+        max=None,
+        max_digits=None,
+        decimal_places=None,
+        rounding=None,
+        dump_as_number=false,
+        custom_encoder=None
+    ))]
     fn new(
-        min: Option<f64>,
-        max: Option<f64>,
+        min: Option<Py<PyAny>>,
+        max: Option<Py<PyAny>>,
+        max_digits: Option<usize>,
+        decimal_places: Option<usize>,
+        rounding: Option<String>,
+        dump_as_number: bool,
         custom_encoder: Option<&Bound<'_, PyAny>>,
     ) -> (Self, BaseType) {
-        (DecimalType { min, max }, BaseType::new(custom_encoder))
+        (
+            DecimalType {
+                min,
+                max,
+                max_digits,
+                decimal_places,
+                rounding,
+                dump_as_number,
+            },
+            BaseType::new(custom_encoder),
+        )
     }
 
     fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
         let base = self_.as_ref();
         let base_other = other.as_ref();
-        Ok(base.__eq__(base_other, py)? && self_.min == other.min && self_.max == other.max)
+        Ok(base.__eq__(base_other, py)?
+            && optional_py_eq(&self_.min, &other.min, py)?
+            && optional_py_eq(&self_.max, &other.max, py)?
+            && self_.max_digits == other.max_digits
+            && self_.decimal_places == other.decimal_places
+            && self_.rounding == other.rounding
+            && self_.dump_as_number == other.dump_as_number)
     }
 
     fn __repr__(&self) -> String {
-        format!("<FloatType: min={:?}, max={:?}>", self.min, self.max)
+        format!(
+            "<DecimalType: min={:?}, max={:?}, max_digits={:?}, decimal_places={:?}, rounding={:?}, dump_as_number={:?}>",
+            self.min, self.max, self.max_digits, self.decimal_places, self.rounding, self.dump_as_number
+        )
+    }
+}
+
+fn optional_py_eq(a: &Option<Py<PyAny>>, b: &Option<Py<PyAny>>, py: Python<'_>) -> PyResult<bool> {
+    match (a, b) {
+        (Some(a), Some(b)) => Ok(py_eq!(a, b, py)),
+        (None, None) => Ok(true),
+        _ => Ok(false),
     }
 }
 
@@ -175,21 +227,29 @@ pub struct StringType {
     pub min_length: Option<usize>,
     #[pyo3(get)]
     pub max_length: Option<usize>,
+    #[pyo3(get)]
+    pub pattern: Option<String>,
+    #[pyo3(get)]
+    pub format: Option<String>,
 }
 
 #[pymethods]
 impl StringType {
     #[new]
-    #[pyo3(signature = (min_length=None, max_length=None, custom_encoder=None))]
+    #[pyo3(signature = (min_length=None, max_length=None, pattern=None, format=None, custom_encoder=None))]
     fn new(
         min_length: Option<usize>,
         max_length: Option<usize>,
+        pattern: Option<String>,
+        format: Option<String>,
         custom_encoder: Option<&Bound<'_, PyAny>>,
     ) -> (Self, BaseType) {
         (
             StringType {
                 min_length,
                 max_length,
+                pattern,
+                format,
             },
             BaseType::new(custom_encoder),
         )
@@ -200,13 +260,15 @@ impl StringType {
         let base_other = other.as_ref();
         Ok(base.__eq__(base_other, py)?
             && self_.min_length == other.min_length
-            && self_.max_length == other.max_length)
+            && self_.max_length == other.max_length
+            && self_.pattern == other.pattern
+            && self_.format == other.format)
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "<StringType: min_length={:?}, max_length={:?}>",
-            self.min_length, self.max_length
+            "<StringType: min_length={:?}, max_length={:?}, pattern={:?}, format={:?}>",
+            self.min_length, self.max_length, self.pattern, self.format
         )
     }
 }
@@ -259,70 +321,166 @@ impl UUIDType {
 
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct TimeType {}
+pub struct TimeType {
+    #[pyo3(get)]
+    pub format: Option<String>,
+}
 
 #[pymethods]
 impl TimeType {
     #[new]
-    #[pyo3(signature = (custom_encoder=None))]
-    fn new(custom_encoder: Option<&Bound<'_, PyAny>>) -> (Self, BaseType) {
-        (TimeType {}, BaseType::new(custom_encoder))
+    #[pyo3(signature = (format=None, custom_encoder=None))]
+    fn new(format: Option<String>, custom_encoder: Option<&Bound<'_, PyAny>>) -> (Self, BaseType) {
+        (TimeType { format }, BaseType::new(custom_encoder))
     }
 
     fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
         let base = self_.as_ref();
         let base_other = other.as_ref();
-        base.__eq__(base_other, py)
+        Ok(base.__eq__(base_other, py)? && self_.format == other.format)
     }
 
     fn __repr__(&self) -> String {
-        "<TimeType>".to_string()
+        format!("<TimeType: format={:?}>", self.format)
     }
 }
 
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct DateTimeType {}
+pub struct DateTimeType {
+    #[pyo3(get)]
+    pub format: Option<String>,
+    #[pyo3(get)]
+    pub timestamp_unit: Option<String>,
+    #[pyo3(get)]
+    pub timestamp_as_float: bool,
+    #[pyo3(get)]
+    pub tz_policy: Option<String>,
+    #[pyo3(get)]
+    pub timezone: Option<String>,
+}
 
 #[pymethods]
 impl DateTimeType {
     #[new]
-    #[pyo3(signature = (custom_encoder=None))]
-    fn new(custom_encoder: Option<&Bound<'_, PyAny>>) -> (Self, BaseType) {
-        (DateTimeType {}, BaseType::new(custom_encoder))
+    #[pyo3(signature = (
+        format=None,
+        timestamp_unit=None,
+        timestamp_as_float=false,
+        tz_policy=None,
+        timezone=None,
+        custom_encoder=None
+    ))]
+    fn new(
+        format: Option<String>,
+        timestamp_unit: Option<String>,
+        timestamp_as_float: bool,
+        tz_policy: Option<String>,
+        timezone: Option<String>,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            DateTimeType {
+                format,
+                timestamp_unit,
+                timestamp_as_float,
+                tz_policy,
+                timezone,
+            },
+            BaseType::new(custom_encoder),
+        )
     }
 
     fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
         let base = self_.as_ref();
         let base_other = other.as_ref();
-        base.__eq__(base_other, py)
+        Ok(base.__eq__(base_other, py)?
+            && self_.format == other.format
+            && self_.timestamp_unit == other.timestamp_unit
+            && self_.timestamp_as_float == other.timestamp_as_float
+            && self_.tz_policy == other.tz_policy
+            && self_.timezone == other.timezone)
     }
 
     fn __repr__(&self) -> String {
-        "<TimeType>".to_string()
+        format!(
+            "<DateTimeType: format={:?}, timestamp_unit={:?}, timestamp_as_float={:?}, tz_policy={:?}, timezone={:?}>",
+            self.format, self.timestamp_unit, self.timestamp_as_float, self.tz_policy, self.timezone
+        )
     }
 }
 
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct DateType {}
+pub struct TimeDeltaType {
+    #[pyo3(get)]
+    pub format: String,
+}
+
+#[pymethods]
+impl TimeDeltaType {
+    #[new]
+    #[pyo3(signature = (format="iso".to_string(), custom_encoder=None))]
+    fn new(format: String, custom_encoder: Option<&Bound<'_, PyAny>>) -> (Self, BaseType) {
+        (TimeDeltaType { format }, BaseType::new(custom_encoder))
+    }
+
+    fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
+        let base = self_.as_ref();
+        let base_other = other.as_ref();
+        Ok(base.__eq__(base_other, py)? && self_.format == other.format)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TimeDeltaType: format={:?}>", self.format)
+    }
+}
+
+#[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateType {
+    #[pyo3(get)]
+    pub format: Option<String>,
+    #[pyo3(get)]
+    pub timestamp_unit: Option<String>,
+    #[pyo3(get)]
+    pub timestamp_as_float: bool,
+}
 
 #[pymethods]
 impl DateType {
     #[new]
-    #[pyo3(signature = (custom_encoder=None))]
-    fn new(custom_encoder: Option<&Bound<'_, PyAny>>) -> (Self, BaseType) {
-        (DateType {}, BaseType::new(custom_encoder))
+    #[pyo3(signature = (format=None, timestamp_unit=None, timestamp_as_float=false, custom_encoder=None))]
+    fn new(
+        format: Option<String>,
+        timestamp_unit: Option<String>,
+        timestamp_as_float: bool,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            DateType {
+                format,
+                timestamp_unit,
+                timestamp_as_float,
+            },
+            BaseType::new(custom_encoder),
+        )
     }
 
     fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
         let base = self_.as_ref();
         let base_other = other.as_ref();
-        base.__eq__(base_other, py)
+        Ok(base.__eq__(base_other, py)?
+            && self_.format == other.format
+            && self_.timestamp_unit == other.timestamp_unit
+            && self_.timestamp_as_float == other.timestamp_as_float)
     }
 
     fn __repr__(&self) -> String {
-        "<TimeType>".to_string()
+        format!(
+            "<DateType: format={:?}, timestamp_unit={:?}, timestamp_as_float={:?}>",
+            self.format, self.timestamp_unit, self.timestamp_as_float
+        )
     }
 }
 
@@ -405,13 +563,25 @@ pub struct EntityType {
     #[pyo3(get)]
     pub is_frozen: bool,
     #[pyo3(get)]
+    pub forbid_extra: bool,
+    #[pyo3(get)]
+    pub call_post_init: bool,
+    #[pyo3(get)]
+    pub use_constructor: bool,
+    /// Values of `InitVar` pseudo-fields passed to `__post_init__`
+    #[pyo3(get)]
+    pub post_init_args: Py<PyTuple>,
+    /// Class level validators of loaded objects
+    #[pyo3(get)]
+    pub validators: Vec<Py<PyAny>>,
+    #[pyo3(get)]
     pub doc: Py<PyAny>,
 }
 
 #[pymethods]
 impl EntityType {
     #[new]
-    #[pyo3(signature = (cls, name, fields, omit_none=false, is_frozen=false, doc=None, custom_encoder=None))]
+    #[pyo3(signature = (cls, name, fields, omit_none=false, is_frozen=false, forbid_extra=false, call_post_init=false, use_constructor=false, post_init_args=None, validators=Vec::new(), doc=None, custom_encoder=None))]
     #[allow(clippy::too_many_arguments)]
     fn new(
         cls: &Bound<'_, PyAny>,
@@ -419,6 +589,11 @@ impl EntityType {
         fields: Vec<EntityField>,
         omit_none: bool,
         is_frozen: bool,
+        forbid_extra: bool,
+        call_post_init: bool,
+        use_constructor: bool,
+        post_init_args: Option<&Bound<'_, PyTuple>>,
+        validators: Vec<Py<PyAny>>,
         doc: Option<&Bound<'_, PyAny>>,
         custom_encoder: Option<&Bound<'_, PyAny>>,
         py: Python<'_>,
@@ -430,6 +605,13 @@ impl EntityType {
                 fields,
                 omit_none,
                 is_frozen,
+                forbid_extra,
+                call_post_init,
+                use_constructor,
+                post_init_args: post_init_args
+                    .map_or_else(|| PyTuple::empty_bound(py), |x| x.clone())
+                    .unbind(),
+                validators,
                 doc: doc.map_or(PyNone::get_bound(py).into_py(py), |x| x.clone().unbind()),
             },
             BaseType::new(custom_encoder),
@@ -449,6 +631,16 @@ impl EntityType {
                 .zip(other.fields.iter())
                 .all(|(a, b)| a.__eq__(b, py).is_ok_and(|x| x))
             && self_.omit_none == other.omit_none
+            && self_.forbid_extra == other.forbid_extra
+            && self_.call_post_init == other.call_post_init
+            && self_.use_constructor == other.use_constructor
+            && py_eq!(self_.post_init_args, other.post_init_args, py)
+            && self_.validators.len() == other.validators.len()
+            && self_
+                .validators
+                .iter()
+                .zip(other.validators.iter())
+                .all(|(a, b)| a.is(b))
             && py_eq!(self_.doc, other.doc, py))
     }
 
@@ -460,11 +652,15 @@ impl EntityType {
             .collect::<Vec<String>>()
             .join(", ");
         format!(
-            "<EntityType: cls={:?}, name={:?}, fields=[{:?}], omit_none={:?}, doc={:?}>",
+            "<EntityType: cls={:?}, name={:?}, fields=[{:?}], omit_none={:?}, forbid_extra={:?}, call_post_init={:?}, use_constructor={:?}, post_init_args={:?}, doc={:?}>",
             self.cls.to_string(),
             self.name.to_string(),
             fields,
             self.omit_none,
+            self.forbid_extra,
+            self.call_post_init,
+            self.use_constructor,
+            self.post_init_args.to_string(),
             self.doc.to_string()
         )
     }
@@ -480,17 +676,20 @@ pub struct TypedDictType {
     #[pyo3(get)]
     pub omit_none: bool,
     #[pyo3(get)]
+    pub forbid_extra: bool,
+    #[pyo3(get)]
     pub doc: Py<PyAny>,
 }
 
 #[pymethods]
 impl TypedDictType {
     #[new]
-    #[pyo3(signature = (name, fields, omit_none=false, doc=None, custom_encoder=None))]
+    #[pyo3(signature = (name, fields, omit_none=false, forbid_extra=false, doc=None, custom_encoder=None))]
     fn new(
         name: &Bound<'_, PyAny>,
         fields: Vec<EntityField>,
         omit_none: bool,
+        forbid_extra: bool,
         doc: Option<&Bound<'_, PyAny>>,
         custom_encoder: Option<&Bound<'_, PyAny>>,
         py: Python<'_>,
@@ -500,6 +699,7 @@ impl TypedDictType {
                 name: name.clone().unbind(),
                 fields,
                 omit_none,
+                forbid_extra,
                 doc: doc.map_or(PyNone::get_bound(py).into_py(py), |x| x.clone().unbind()),
             },
             BaseType::new(custom_encoder),
@@ -518,6 +718,7 @@ impl TypedDictType {
                 .zip(other.fields.iter())
                 .all(|(a, b)| a.__eq__(b, py).is_ok_and(|x| x))
             && self_.omit_none == other.omit_none
+            && self_.forbid_extra == other.forbid_extra
             && py_eq!(self_.doc, other.doc, py))
     }
 
@@ -529,10 +730,11 @@ impl TypedDictType {
             .collect::<Vec<String>>()
             .join(", ");
         format!(
-            "<TypedDictType: name={:?}, fields=[{:?}], omit_none={:?}, doc={:?}>",
+            "<TypedDictType: name={:?}, fields=[{:?}], omit_none={:?}, forbid_extra={:?}, doc={:?}>",
             self.name.to_string(),
             fields,
             self.omit_none,
+            self.forbid_extra,
             self.doc.to_string()
         )
     }
@@ -552,6 +754,20 @@ pub struct EntityField {
     #[pyo3(get)]
     pub is_discriminator_field: bool,
     #[pyo3(get)]
+    pub is_extra_fields: bool,
+    /// Dumped only, ignored on load
+    #[pyo3(get)]
+    pub read_only: bool,
+    /// Loaded only, skipped on dump
+    #[pyo3(get)]
+    pub write_only: bool,
+    /// Passed to the class constructor, otherwise set after the object is created
+    #[pyo3(get)]
+    pub init: bool,
+    /// Name of the constructor argument if it differs from the field name (e.g. attrs private fields)
+    #[pyo3(get)]
+    pub init_alias: Option<Py<PyAny>>,
+    #[pyo3(get)]
     pub default: DefaultValue,
     #[pyo3(get)]
     pub default_factory: DefaultValue,
@@ -562,7 +778,7 @@ pub struct EntityField {
 #[pymethods]
 impl EntityField {
     #[new]
-    #[pyo3(signature = (name, dict_key, field_type, required=true, is_discriminator_field=false, default=DefaultValue(DefaultValueEnum::None), default_factory=DefaultValue(DefaultValueEnum::None), doc=None))]
+    #[pyo3(signature = (name, dict_key, field_type, required=true, is_discriminator_field=false, is_extra_fields=false, read_only=false, write_only=false, init=true, init_alias=None, default=DefaultValue(DefaultValueEnum::None), default_factory=DefaultValue(DefaultValueEnum::None), doc=None))]
     #[allow(clippy::too_many_arguments)]
     fn new(
         name: &Bound<'_, PyAny>,
@@ -570,6 +786,11 @@ impl EntityField {
         field_type: &Bound<'_, PyAny>,
         required: bool,
         is_discriminator_field: bool,
+        is_extra_fields: bool,
+        read_only: bool,
+        write_only: bool,
+        init: bool,
+        init_alias: Option<&Bound<'_, PyAny>>,
         default: DefaultValue,
         default_factory: DefaultValue,
         doc: Option<&Bound<'_, PyAny>>,
@@ -581,6 +802,11 @@ impl EntityField {
             field_type: field_type.clone().clone().unbind(),
             required,
             is_discriminator_field,
+            is_extra_fields,
+            read_only,
+            write_only,
+            init,
+            init_alias: init_alias.map(|x| x.clone().unbind()),
             doc: doc.map_or(PyNone::get_bound(py).into_py(py), |x| x.clone().unbind()),
             default,
             default_factory,
@@ -593,13 +819,18 @@ impl EntityField {
             && py_eq!(self.field_type, other.field_type, py)
             && self.required == other.required
             && self.is_discriminator_field == other.is_discriminator_field
+            && self.is_extra_fields == other.is_extra_fields
+            && self.read_only == other.read_only
+            && self.write_only == other.write_only
+            && self.init == other.init
+            && optional_py_eq(&self.init_alias, &other.init_alias, py)?
             && self.default == other.default
             && self.default_factory == other.default_factory
             && py_eq!(self.doc, other.doc, py))
     }
 
     fn __repr__(&self) -> String {
-        format!("<EntityField: name={:?}, dict_key={:?}, field_type={:?}, required={:?}, is_discriminator_field={:?}, default={:?}, default_factory={:?}, doc={:?}>", self.name.to_string(), self.dict_key.to_string(), self.field_type.to_string(), self.required, self.is_discriminator_field, self.default, self.default_factory, self.doc.to_string())
+        format!("<EntityField: name={:?}, dict_key={:?}, field_type={:?}, required={:?}, is_discriminator_field={:?}, is_extra_fields={:?}, read_only={:?}, write_only={:?}, init={:?}, init_alias={:?}, default={:?}, default_factory={:?}, doc={:?}>", self.name.to_string(), self.dict_key.to_string(), self.field_type.to_string(), self.required, self.is_discriminator_field, self.is_extra_fields, self.read_only, self.write_only, self.init, self.init_alias.as_ref().map(|x| x.to_string()), self.default, self.default_factory, self.doc.to_string())
     }
 }
 
@@ -759,6 +990,99 @@ impl OptionalType {
     }
 }
 
+#[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
+#[derive(Debug, Clone)]
+pub struct ValidatedType {
+    #[pyo3(get)]
+    pub inner: Py<PyAny>,
+    #[pyo3(get)]
+    pub validators: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl ValidatedType {
+    #[new]
+    #[pyo3(signature = (inner, validators, custom_encoder=None))]
+    fn new(
+        inner: &Bound<'_, PyAny>,
+        validators: Vec<Py<PyAny>>,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            ValidatedType {
+                inner: inner.clone().unbind(),
+                validators,
+            },
+            BaseType::new(custom_encoder),
+        )
+    }
+
+    fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
+        let base = self_.as_ref();
+        let base_other = other.as_ref();
+        Ok(base.__eq__(base_other, py)?
+            && py_eq!(self_.inner, other.inner, py)
+            && self_.validators.len() == other.validators.len()
+            && self_
+                .validators
+                .iter()
+                .zip(other.validators.iter())
+                .all(|(a, b)| a.is(b)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ValidatedType: inner={:?}, validators={:?}>",
+            self.inner.to_string(),
+            self.validators
+        )
+    }
+}
+
+#[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
+#[derive(Debug, Clone)]
+pub struct CoercionType {
+    #[pyo3(get)]
+    pub inner: Py<PyAny>,
+    #[pyo3(get)]
+    pub mode: String,
+}
+
+#[pymethods]
+impl CoercionType {
+    #[new]
+    #[pyo3(signature = (inner, mode, custom_encoder=None))]
+    fn new(
+        inner: &Bound<'_, PyAny>,
+        mode: String,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            CoercionType {
+                inner: inner.clone().unbind(),
+                mode,
+            },
+            BaseType::new(custom_encoder),
+        )
+    }
+
+    fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
+        let base = self_.as_ref();
+        let base_other = other.as_ref();
+        Ok(base.__eq__(base_other, py)?
+            && py_eq!(self_.inner, other.inner, py)
+            && self_.mode == other.mode)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<CoercionType: inner={:?}, mode={:?}>",
+            self.inner.to_string(),
+            self.mode
+        )
+    }
+}
+
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
 #[derive(Debug, Clone)]
 pub struct DictionaryType {
@@ -855,6 +1179,112 @@ impl TupleType {
     }
 }
 
+#[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
+#[derive(Debug, Clone)]
+pub struct VarTupleType {
+    #[pyo3(get)]
+    pub item_type: Py<PyAny>,
+    #[pyo3(get)]
+    pub min_length: Option<usize>,
+    #[pyo3(get)]
+    pub max_length: Option<usize>,
+}
+
+#[pymethods]
+impl VarTupleType {
+    #[new]
+    #[pyo3(signature = (item_type, min_length=None, max_length=None, custom_encoder=None))]
+    fn new(
+        item_type: &Bound<'_, PyAny>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            VarTupleType {
+                item_type: item_type.clone().unbind(),
+                min_length,
+                max_length,
+            },
+            BaseType::new(custom_encoder),
+        )
+    }
+
+    fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
+        let base = self_.as_ref();
+        let base_other = other.as_ref();
+        Ok(base.__eq__(base_other, py)?
+            && py_eq!(self_.item_type, other.item_type, py)
+            && self_.min_length == other.min_length
+            && self_.max_length == other.max_length)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<VarTupleType: item_type={:?}, min_length={:?}, max_length={:?}>",
+            self.item_type.to_string(),
+            self.min_length,
+            self.max_length
+        )
+    }
+}
+
+#[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
+#[derive(Debug, Clone)]
+pub struct SetType {
+    #[pyo3(get)]
+    pub item_type: Py<PyAny>,
+    #[pyo3(get)]
+    pub frozen: bool,
+    #[pyo3(get)]
+    pub forbid_duplicates: bool,
+    #[pyo3(get)]
+    pub sort_on_dump: bool,
+}
+
+#[pymethods]
+impl SetType {
+    #[new]
+    #[pyo3(signature = (item_type, frozen=false, forbid_duplicates=false, sort_on_dump=false, custom_encoder=None))]
+    fn new(
+        item_type: &Bound<'_, PyAny>,
+        frozen: bool,
+        forbid_duplicates: bool,
+        sort_on_dump: bool,
+        custom_encoder: Option<&Bound<'_, PyAny>>,
+    ) -> (Self, BaseType) {
+        (
+            SetType {
+                item_type: item_type.clone().unbind(),
+                frozen,
+                forbid_duplicates,
+                sort_on_dump,
+            },
+            BaseType::new(custom_encoder),
+        )
+    }
+
+    fn __eq__(self_: PyRef<'_, Self>, other: PyRef<'_, Self>, py: Python<'_>) -> PyResult<bool> {
+        let base = self_.as_ref();
+        let base_other = other.as_ref();
+        Ok(base.__eq__(base_other, py)?
+            && py_eq!(self_.item_type, other.item_type, py)
+            && self_.frozen == other.frozen
+            && self_.forbid_duplicates == other.forbid_duplicates
+            && self_.sort_on_dump == other.sort_on_dump)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<SetType: item_type={:?}, frozen={:?}, forbid_duplicates={:?}, sort_on_dump={:?}>",
+            self.item_type.to_string(),
+            self.frozen,
+            self.forbid_duplicates,
+            self.sort_on_dump
+        )
+    }
+}
+
 #[pyclass(frozen, extends=BaseType, module="serpyco_rs")]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BytesType {}
@@ -910,16 +1340,24 @@ pub struct DiscriminatedUnionType {
     pub dump_discriminator: Py<PyAny>,
     #[pyo3(get)]
     pub load_discriminator: Py<PyAny>,
+    /// Discriminator value used when the discriminator field is missing
+    #[pyo3(get)]
+    pub default_tag: Option<Py<PyAny>>,
+    /// Type of values with unknown discriminator values
+    #[pyo3(get)]
+    pub fallback: Option<Py<PyAny>>,
 }
 
 #[pymethods]
 impl DiscriminatedUnionType {
     #[new]
-    #[pyo3(signature = (item_types, dump_discriminator, load_discriminator, custom_encoder=None))]
+    #[pyo3(signature = (item_types, dump_discriminator, load_discriminator, default_tag=None, fallback=None, custom_encoder=None))]
     fn new(
         item_types: &Bound<'_, PyAny>,
         dump_discriminator: &Bound<'_, PyAny>,
         load_discriminator: &Bound<'_, PyAny>,
+        default_tag: Option<&Bound<'_, PyAny>>,
+        fallback: Option<&Bound<'_, PyAny>>,
         custom_encoder: Option<&Bound<'_, PyAny>>,
     ) -> (Self, BaseType) {
         (
@@ -927,6 +1365,8 @@ impl DiscriminatedUnionType {
                 item_types: item_types.clone().unbind(),
                 dump_discriminator: dump_discriminator.clone().unbind(),
                 load_discriminator: load_discriminator.clone().unbind(),
+                default_tag: default_tag.map(|x| x.clone().unbind()),
+                fallback: fallback.map(|x| x.clone().unbind()),
             },
             BaseType::new(custom_encoder),
         )
@@ -938,15 +1378,19 @@ impl DiscriminatedUnionType {
         Ok(base.__eq__(base_other, py)?
             && py_eq!(self_.item_types, other.item_types, py)
             && py_eq!(self_.dump_discriminator, other.dump_discriminator, py)
-            && py_eq!(self_.load_discriminator, other.load_discriminator, py))
+            && py_eq!(self_.load_discriminator, other.load_discriminator, py)
+            && optional_py_eq(&self_.default_tag, &other.default_tag, py)?
+            && optional_py_eq(&self_.fallback, &other.fallback, py)?)
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "<DiscriminatedUnionType: item_types={:?}, dump_discriminator={:?}, load_discriminator={:?}>",
+            "<DiscriminatedUnionType: item_types={:?}, dump_discriminator={:?}, load_discriminator={:?}, default_tag={:?}, fallback={:?}>",
             self.item_types.to_string(),
             self.dump_discriminator.to_string(),
             self.load_discriminator.to_string(),
+            self.default_tag.as_ref().map(|x| x.to_string()),
+            self.fallback.as_ref().map(|x| x.to_string()),
         )
     }
 }
@@ -957,21 +1401,25 @@ pub struct UnionType {
     #[pyo3(get)]
     pub item_types: Py<PyAny>,
     pub union_repr: String,
+    #[pyo3(get)]
+    pub smart: bool,
 }
 
 #[pymethods]
 impl UnionType {
     #[new]
-    #[pyo3(signature = (item_types, union_repr, custom_encoder=None))]
+    #[pyo3(signature = (item_types, union_repr, smart=false, custom_encoder=None))]
     fn new(
         item_types: &Bound<'_, PyAny>,
         union_repr: String,
+        smart: bool,
         custom_encoder: Option<&Bound<'_, PyAny>>,
     ) -> (Self, BaseType) {
         (
             UnionType {
                 item_types: item_types.clone().unbind(),
                 union_repr,
+                smart,
             },
             BaseType::new(custom_encoder),
         )
@@ -982,11 +1430,16 @@ impl UnionType {
         let base_other = other.as_ref();
         Ok(base.__eq__(base_other, py)?
             && py_eq!(self_.item_types, other.item_types, py)
-            && self_.union_repr == other.union_repr)
+            && self_.union_repr == other.union_repr
+            && self_.smart == other.smart)
     }
 
     fn __repr__(&self) -> String {
-        format!("<UnionType: item_types={:?}>", self.item_types.to_string(),)
+        format!(
+            "<UnionType: item_types={:?}, smart={:?}>",
+            self.item_types.to_string(),
+            self.smart
+        )
     }
 }
 
@@ -1085,7 +1538,7 @@ impl RecursionHolder {
         )
     }
 
-    pub fn get_inner_type<'a>(&'a self, py: Python<'a>) -> PyResult<Bound<'_, pyo3::PyAny>> {
+    pub fn get_inner_type<'a>(&'a self, py: Python<'a>) -> PyResult<Bound<'a, pyo3::PyAny>> {
         match self.meta.bind(py).get_item(&self.state_key) {
             Ok(type_) => Ok(type_),
             Err(e) => Err(PyErr::new::<PyRuntimeError, _>(format!(
@@ -1,8 +1,10 @@
+use crate::validator::formats::StringFormat;
 use crate::validator::{raise_error, InstancePath};
 
 use pyo3::prelude::PyAnyMethods;
-use pyo3::types::{PyList, PySequence, PyString};
-use pyo3::{Bound, PyAny, PyErr, PyResult};
+use pyo3::types::{PySequence, PyString, PyStringMethods};
+use pyo3::{intern, Bound, PyAny, PyErr, PyResult};
+use regex::Regex;
 use std::cmp::Ordering;
 use std::fmt::Display;
 
@@ -108,6 +110,38 @@ pub fn check_length(
     Ok(())
 }
 
+pub fn check_pattern(
+    val: &Bound<'_, PyString>,
+    pattern: Option<&Regex>,
+    instance_path: &InstancePath,
+) -> PyResult<()> {
+    if let Some(pattern) = pattern {
+        if !pattern.is_match(val.to_str()?) {
+            raise_error(
+                format!(r#""{}" does not match "{}""#, val, pattern.as_str()),
+                instance_path,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub fn check_format(
+    val: &Bound<'_, PyString>,
+    format: Option<StringFormat>,
+    instance_path: &InstancePath,
+) -> PyResult<()> {
+    if let Some(format) = format {
+        if !format.is_valid(val.to_str()?) {
+            raise_error(
+                format!(r#""{}" is not a valid "{}""#, val, format),
+                instance_path,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 #[cold]
 pub fn missing_required_property(property: &str, instance_path: &InstancePath) -> PyErr {
     let instance_path = instance_path.push(property);
@@ -118,6 +152,26 @@ pub fn missing_required_property(property: &str, instance_path: &InstancePath) -
     .unwrap_err()
 }
 
+#[cold]
+pub fn unexpected_property(property: &str, instance_path: &InstancePath) -> PyErr {
+    let instance_path = instance_path.push(property);
+    raise_error(
+        format!(r#"Additional property "{}" is not allowed"#, property),
+        &instance_path,
+    )
+    .unwrap_err()
+}
+
+#[cold]
+pub fn read_only_property(property: &str, instance_path: &InstancePath) -> PyErr {
+    let instance_path = instance_path.push(property);
+    raise_error(
+        format!(r#"Property "{}" is read-only"#, property),
+        &instance_path,
+    )
+    .unwrap_err()
+}
+
 pub fn check_sequence_size(
     val: &Bound<'_, PySequence>,
     seq_len: usize,
@@ -144,7 +198,7 @@ pub fn check_sequence_size(
 }
 
 pub fn check_sequence_bounds(
-    val: &Bound<'_, PyList>,
+    val: &Bound<'_, PyAny>,
     seq_len: usize,
     min: Option<usize>,
     max: Option<usize>,
@@ -174,6 +228,95 @@ pub fn check_sequence_bounds(
     Ok(())
 }
 
+pub fn check_unique_items(
+    val: &Bound<'_, PyAny>,
+    items_count: usize,
+    unique_count: usize,
+    instance_path: &InstancePath,
+) -> PyResult<()> {
+    if unique_count < items_count {
+        raise_error(
+            format!("{} has non-unique elements", fmt_py(val)),
+            instance_path,
+        )?;
+    }
+    Ok(())
+}
+
+/// Python `Decimal` compared in decimal arithmetic, so bounds are checked without losing precision.
+#[derive(Clone, Copy)]
+pub struct DecimalValue<'a, 'py>(pub &'a Bound<'py, PyAny>);
+
+impl PartialEq for DecimalValue<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for DecimalValue<'_, '_> {
+    /// `NaN` is not comparable, the same as `f64::NAN`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.compare(other.0).ok()
+    }
+}
+
+impl Display for DecimalValue<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+pub fn check_decimal_digits(
+    val: &Bound<'_, PyAny>,
+    max_digits: Option<usize>,
+    decimal_places: Option<usize>,
+    instance_path: &InstancePath,
+) -> PyResult<()> {
+    if max_digits.is_none() && decimal_places.is_none() {
+        return Ok(());
+    }
+    let (_, digits, exponent): (Bound<'_, PyAny>, Vec<u8>, Bound<'_, PyAny>) =
+        val.call_method0(intern!(val.py(), "as_tuple"))?.extract()?;
+    // The exponent is a string for NaN and infinity
+    let Ok(mut exponent) = exponent.extract::<i64>() else {
+        raise_error(format!("{} is not a finite number", val), instance_path)?;
+        unreachable!()
+    };
+    // Trailing zeros of the fractional part are not significant: 1.50 has 2 digits and 1 decimal place
+    let mut digits_count = digits.len();
+    while exponent < 0 && digits_count > 1 && digits[digits_count - 1] == 0 {
+        digits_count -= 1;
+        exponent += 1;
+    }
+    if digits[..digits_count].iter().all(|&d| d == 0) {
+        (digits_count, exponent) = (1, 0);
+    }
+    let (total_digits, decimals) = match usize::try_from(exponent) {
+        Ok(exponent) => (digits_count + exponent, 0),
+        Err(_) => {
+            let decimals = exponent.unsigned_abs() as usize;
+            (digits_count.max(decimals), decimals)
+        }
+    };
+    if let Some(max_digits) = max_digits {
+        if total_digits > max_digits {
+            raise_error(
+                format!("{} has more than {} digits", val, max_digits),
+                instance_path,
+            )?;
+        }
+    }
+    if let Some(decimal_places) = decimal_places {
+        if decimals > decimal_places {
+            raise_error(
+                format!("{} has more than {} decimal places", val, decimal_places),
+                instance_path,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 pub fn no_encoder_for_discriminator<K, D>(
     key: &K,
     discriminators: &[D],
@@ -185,14 +328,11 @@ where
 {
     let items = discriminators
         .iter()
-        .map(|s| format!(r#""{}""#, s))
+        .map(|s| s.to_string())
         .collect::<Vec<_>>()
         .join(", ");
     raise_error(
-        format!(
-            r#""{}" is not one of [{}] discriminator values"#,
-            key, items
-        ),
+        format!("{} is not one of [{}] discriminator values", key, items),
         instance_path,
     )
     .unwrap_err()
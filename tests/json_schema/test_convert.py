@@ -1,6 +1,6 @@
 import sys
 from dataclasses import dataclass
-from datetime import datetime, time
+from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from enum import Enum
 from typing import Annotated, Any, Literal, Optional, TypedDict, Union
@@ -8,7 +8,27 @@ from uuid import UUID
 
 import pytest
 from serpyco_rs import Serializer, JsonSchemaBuilder
-from serpyco_rs.metadata import Alias, CamelCase, Discriminator, Max, MaxLength, Min, MinLength, OmitNone
+from serpyco_rs.metadata import (
+    Alias,
+    CamelCase,
+    DateFormat,
+    DecimalAsNumber,
+    Discriminator,
+    ExtraFields,
+    IsoDuration,
+    Max,
+    MaxLength,
+    Min,
+    MinLength,
+    OmitNone,
+    Pattern,
+    ReadOnly,
+    StringFormat,
+    Timestamp,
+    TotalSeconds,
+    TotalSecondsInt,
+    WriteOnly,
+)
 
 
 def test_to_json_schema():
@@ -337,6 +357,64 @@ def test_to_json_schema__tagged_union():
     }
 
 
+def test_to_json_schema__tagged_union__fallback():
+    @dataclass
+    class Foo:
+        type: Literal['foo']
+
+    @dataclass
+    class Bar:
+        type: Literal['bar']
+
+    serializer = Serializer(Annotated[Union[Foo, Bar], Discriminator('type', fallback=dict[str, Any])])
+    schema = serializer.get_json_schema()
+
+    assert schema['discriminator']['mapping'].keys() == {'foo', 'bar'}
+    assert schema['oneOf'][-1] == {'additionalProperties': {}, 'type': 'object'}
+
+
+def test_to_json_schema__tagged_union__nested_discriminator():
+    @dataclass
+    class FooMeta:
+        kind: Literal['foo']
+
+    @dataclass
+    class Foo:
+        meta: FooMeta
+
+    @dataclass
+    class BarMeta:
+        kind: Literal['bar']
+
+    @dataclass
+    class Bar:
+        meta: BarMeta
+
+    schema = Serializer(Annotated[Union[Foo, Bar], Discriminator('meta.kind')]).get_json_schema()
+
+    assert 'discriminator' not in schema
+    assert len(schema['oneOf']) == 2
+
+
+def test_to_json_schema__tagged_union__int_and_bool_discriminator():
+    @dataclass
+    class Foo:
+        type: Literal[1]
+
+    @dataclass
+    class Bar:
+        type: Literal[False]
+
+    schema = Serializer(Annotated[Union[Foo, Bar], Discriminator('type')]).get_json_schema()
+
+    assert schema['discriminator']['mapping'].keys() == {'1', 'false'}
+    assert schema['components']['schemas'][schema['discriminator']['mapping']['1'].split('/')[-1]] == {
+        'properties': {'type': {'enum': [1]}},
+        'required': ['type'],
+        'type': 'object',
+    }
+
+
 def test_to_json_schema__union():
     @dataclass
     class Foo:
@@ -470,6 +548,98 @@ def test_array_length():
     }
 
 
+def test_variable_length_tuple():
+    serializer = Serializer(Annotated[tuple[int, ...], MinLength(1), MaxLength(10)])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'type': 'array',
+        'items': {'type': 'integer'},
+        'minItems': 1,
+        'maxItems': 10,
+    }
+
+
+def test_set():
+    serializer = Serializer(frozenset[str])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'type': 'array',
+        'items': {'type': 'string'},
+        'uniqueItems': True,
+    }
+
+
+@pytest.mark.parametrize(
+    ['annotation', 'expected'],
+    [
+        (IsoDuration, {'type': 'string', 'format': 'duration'}),
+        (TotalSeconds, {'type': 'number'}),
+        (TotalSecondsInt, {'type': 'integer'}),
+    ],
+)
+def test_timedelta(annotation, expected):
+    serializer = Serializer(Annotated[timedelta, annotation])
+    assert serializer.get_json_schema() == {'$schema': 'https://json-schema.org/draft/2020-12/schema', **expected}
+
+
+@pytest.mark.parametrize(
+    ['timestamp', 'expected'],
+    [
+        (Timestamp(unit='ms'), {'type': 'integer'}),
+        (Timestamp(as_float=True), {'type': 'number'}),
+    ],
+)
+def test_datetime_timestamp(timestamp, expected):
+    for t in (date, datetime):
+        serializer = Serializer(Annotated[t, timestamp])
+        assert serializer.get_json_schema() == {'$schema': 'https://json-schema.org/draft/2020-12/schema', **expected}
+
+
+@pytest.mark.parametrize('t', [date, time, datetime])
+def test_date_format(t):
+    serializer = Serializer(Annotated[t, DateFormat('%d.%m.%Y')])
+    assert serializer.get_json_schema() == {'$schema': 'https://json-schema.org/draft/2020-12/schema', 'type': 'string'}
+
+
+def test_decimal_as_number():
+    serializer = Serializer(Annotated[Decimal, DecimalAsNumber])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'type': 'number',
+        'format': 'decimal',
+    }
+
+
+def test_decimal_min_max():
+    serializer = Serializer(Annotated[Decimal, Min(Decimal('0.5')), Max(10)])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'oneOf': [
+            {'type': 'string', 'format': 'decimal'},
+            {'type': 'number', 'format': 'decimal', 'minimum': 0.5, 'maximum': 10},
+        ],
+    }
+
+
+def test_string_pattern():
+    serializer = Serializer(Annotated[str, Pattern(r'^\d+$'), MaxLength(10)])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'type': 'string',
+        'maxLength': 10,
+        'pattern': r'^\d+$',
+    }
+
+
+def test_string_format():
+    serializer = Serializer(Annotated[str, StringFormat('email')])
+    assert serializer.get_json_schema() == {
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'type': 'string',
+        'format': 'email',
+    }
+
+
 def test_one_dataclass_with_different_annotations__should_generate_different_schemas():
     @dataclass
     class Foo:
@@ -513,6 +683,93 @@ def test_one_dataclass_with_different_annotations__should_generate_different_sch
     }
 
 
+def test_to_json_schema__forbid_extra():
+    @dataclass
+    class Data:
+        a: int
+
+    serializer = Serializer(Data, forbid_extra=True)
+    assert serializer.get_json_schema() == {
+        '$ref': '#/components/schemas/tests.json_schema.test_convert.test_to_json_schema__forbid_extra.<locals>.Data',
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'components': {
+            'schemas': {
+                'tests.json_schema.test_convert.test_to_json_schema__forbid_extra.<locals>.Data': {
+                    'properties': {'a': {'type': 'integer'}},
+                    'additionalProperties': False,
+                    'type': 'object',
+                    'required': ['a'],
+                }
+            }
+        },
+    }
+
+
+def test_to_json_schema__extra_fields():
+    @dataclass
+    class Data:
+        a: int
+        extra: Annotated[dict[str, int], ExtraFields]
+
+    serializer = Serializer(Data)
+    assert serializer.get_json_schema() == {
+        '$ref': '#/components/schemas/tests.json_schema.test_convert.test_to_json_schema__extra_fields.<locals>.Data',
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'components': {
+            'schemas': {
+                'tests.json_schema.test_convert.test_to_json_schema__extra_fields.<locals>.Data': {
+                    'properties': {'a': {'type': 'integer'}},
+                    'additionalProperties': {'type': 'integer'},
+                    'type': 'object',
+                    'required': ['a'],
+                }
+            }
+        },
+    }
+
+
+def test_to_json_schema__read_only_write_only():
+    @dataclass
+    class Inner:
+        a: int
+
+    @dataclass
+    class Data:
+        password: Annotated[str, WriteOnly]
+        nested: Annotated[Inner, WriteOnly]
+        id: Annotated[int, ReadOnly] = 0
+        inner: Annotated[Optional[Inner], ReadOnly] = None
+
+    prefix = 'tests.json_schema.test_convert.test_to_json_schema__read_only_write_only.<locals>'
+    serializer = Serializer(Data)
+    assert serializer.get_json_schema() == {
+        '$ref': f'#/components/schemas/{prefix}.Data',
+        '$schema': 'https://json-schema.org/draft/2020-12/schema',
+        'components': {
+            'schemas': {
+                f'{prefix}.Data': {
+                    'properties': {
+                        'password': {'type': 'string', 'writeOnly': True},
+                        'nested': {'$ref': f'#/components/schemas/{prefix}.Inner', 'writeOnly': True},
+                        'id': {'type': 'integer', 'readOnly': True},
+                        'inner': {
+                            'anyOf': [{'type': 'null'}, {'$ref': f'#/components/schemas/{prefix}.Inner'}],
+                            'readOnly': True,
+                        },
+                    },
+                    'type': 'object',
+                    'required': ['password', 'nested'],
+                },
+                f'{prefix}.Inner': {
+                    'properties': {'a': {'type': 'integer'}},
+                    'type': 'object',
+                    'required': ['a'],
+                },
+            }
+        },
+    }
+
+
 class TestJsonSchemaBuilder:
     def test_build__use_custom_ref_prefix(self):
         @dataclass
@@ -1,5 +1,6 @@
 import sys
-from collections.abc import Mapping, Sequence
+from collections.abc import Mapping, MutableSet, Sequence
+from collections.abc import Set as AbstractSet
 from dataclasses import dataclass, field
 from datetime import date, datetime, time
 from decimal import Decimal
@@ -16,6 +17,7 @@ from serpyco_rs._describe import (
     AnyType,
     ArrayType,
     BooleanType,
+    CoercionType,
     DateTimeType,
     DateType,
     DecimalType,
@@ -28,6 +30,7 @@ from serpyco_rs._describe import (
     IntegerType,
     LiteralType,
     OptionalType,
+    SetType,
     StringType,
     TimeType,
     TupleType,
@@ -35,9 +38,30 @@ from serpyco_rs._describe import (
     UnionType,
     DiscriminatedUnionType,
     UUIDType,
+    VarTupleType,
     describe_type,
 )
-from serpyco_rs.metadata import Alias, CamelCase, Discriminator, Max, MaxLength, Min, MinLength, NoFormat
+from serpyco_rs.metadata import (
+    Alias,
+    AssumeTimezone,
+    CamelCase,
+    DateFormat,
+    DecimalAsNumber,
+    DecimalPlaces,
+    Discriminator,
+    ForbidDuplicates,
+    Max,
+    MaxDigits,
+    MaxLength,
+    Min,
+    MinLength,
+    NoFormat,
+    RequireAware,
+    SmartUnion,
+    SortedItems,
+    Strict,
+    Timestamp,
+)
 from typing_extensions import NotRequired, Required, TypedDict
 
 
@@ -497,9 +521,31 @@ def test_describe__type_with_typevar__fail():
 
 def test_describe__unknown_type__fail():
     with pytest.raises(RuntimeError) as exc_info:
-        describe_type(set[int])
+        describe_type(complex)
+
+    assert exc_info.match("Unknown type <class 'complex'>")
 
-    assert exc_info.match("Unknown type <class 'set'>")
+
+@pytest.mark.parametrize(
+    ['t', 'frozen'],
+    [
+        (set[int], False),
+        (frozenset[int], True),
+        (AbstractSet[int], True),
+        (MutableSet[int], False),
+    ],
+)
+def test_describe__set__parsed(t, frozen):
+    assert describe_type(t) == SetType(item_type=IntegerType(custom_encoder=None), frozen=frozen, custom_encoder=None)
+
+
+def test_describe__set_with_annotations__parsed():
+    assert describe_type(Annotated[set[str], ForbidDuplicates, SortedItems]) == SetType(
+        item_type=StringType(custom_encoder=None),
+        forbid_duplicates=True,
+        sort_on_dump=True,
+        custom_encoder=None,
+    )
 
 
 def test_describe__optional__wrapped():
@@ -514,6 +560,13 @@ def test_describe__unions():
     )
 
 
+def test_describe__smart_union():
+    union_type = describe_type(Annotated[Union[int, str], SmartUnion])
+    assert isinstance(union_type, UnionType)
+    assert union_type.item_types == [IntegerType(custom_encoder=None), StringType(custom_encoder=None)]
+    assert union_type.smart is True
+
+
 @pytest.mark.skipif(sys.version_info < (3, 10), reason='New style unions available after 3.10')
 def test_describe__new_style_union_type__wrapped():
     assert describe_type(int | None) == OptionalType(inner=IntegerType(custom_encoder=None), custom_encoder=None)
@@ -526,12 +579,55 @@ def test_describe__tuple__parsed():
     )
 
 
-@pytest.mark.parametrize('t', [tuple, tuple[int, ...]])
-def test_describe__invalid_tuple__error(t):
-    with pytest.raises(RuntimeError) as exc_info:
-        describe_type(t)
+def test_describe__variable_length_tuple__parsed():
+    assert describe_type(Annotated[tuple[int, ...], MinLength(1), MaxLength(3)]) == VarTupleType(
+        item_type=IntegerType(custom_encoder=None),
+        min_length=1,
+        max_length=3,
+        custom_encoder=None,
+    )
+
 
-    assert exc_info.match('Variable length tuples are not supported')
+def test_describe__bare_tuple__parsed_as_variable_length_tuple_of_any():
+    assert describe_type(tuple) == VarTupleType(item_type=AnyType(custom_encoder=None), custom_encoder=None)
+
+
+def test_describe__date_format__parsed():
+    assert describe_type(Annotated[date, DateFormat('%d.%m.%Y')]) == DateType(format='%d.%m.%Y', custom_encoder=None)
+    assert describe_type(Annotated[time, DateFormat('%H:%M')]) == TimeType(format='%H:%M', custom_encoder=None)
+    assert describe_type(Annotated[datetime, DateFormat('%Y %H')]) == DateTimeType(format='%Y %H', custom_encoder=None)
+    assert describe_type(date) == DateType(custom_encoder=None)
+    assert describe_type(Annotated[date, Timestamp(unit='ms')]) == DateType(timestamp_unit='ms', custom_encoder=None)
+
+
+def test_describe__timezone_policy__parsed():
+    assert describe_type(Annotated[datetime, RequireAware]) == DateTimeType(
+        tz_policy='require_aware', custom_encoder=None
+    )
+    assert describe_type(Annotated[datetime, AssumeTimezone('Europe/Berlin')]) == DateTimeType(
+        tz_policy='assume_timezone', timezone='Europe/Berlin', custom_encoder=None
+    )
+
+
+def test_describe__decimal__parsed():
+    assert describe_type(
+        Annotated[Decimal, Min(0), MaxDigits(10), DecimalPlaces(2, rounding='ROUND_HALF_UP'), DecimalAsNumber]
+    ) == DecimalType(
+        min=0,
+        max_digits=10,
+        decimal_places=2,
+        rounding='ROUND_HALF_UP',
+        dump_as_number=True,
+        custom_encoder=None,
+    )
+
+
+def test_describe__coercion__parsed():
+    assert describe_type(Annotated[list[int], Strict]) == CoercionType(
+        inner=ArrayType(item_type=IntegerType(custom_encoder=None), custom_encoder=None),
+        mode='strict',
+        custom_encoder=None,
+    )
 
 
 def test_describe__dataclass_field_format__parsed():
@@ -0,0 +1,164 @@
+from dataclasses import dataclass
+from decimal import Decimal
+from typing import Annotated, Any
+
+import pytest
+from multidict import MultiDict
+from serpyco_rs import SchemaValidationError, Serializer
+from serpyco_rs._impl import ErrorItem
+from serpyco_rs.metadata import CoercionMode, DefaultCoercion, Lax, Strict
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'expected'],
+    [
+        (int, 1, 1),
+        (int, True, True),
+        (float, 1, 1),
+        (float, 1.5, 1.5),
+        (bool, True, True),
+        (Decimal, '1.5', Decimal('1.5')),
+        (Decimal, 1, Decimal('1')),
+        (Decimal, Decimal('1.5'), Decimal('1.5')),
+        (Decimal, True, Decimal('1')),
+    ],
+)
+def test_default_mode(t, value, expected):
+    assert Serializer(t).load(value) == expected
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'err'],
+    [
+        (int, '1', '"1" is not of type "integer"'),
+        (int, 1.0, '1.0 is not of type "integer"'),
+        (float, '1.5', '"1.5" is not of type "number"'),
+        (bool, 1, '1 is not of type "boolean"'),
+        (bool, 'true', '"true" is not of type "boolean"'),
+    ],
+)
+def test_default_mode__invalid(t, value, err):
+    with pytest.raises(SchemaValidationError) as e:
+        Serializer(t).load(value)
+    assert e.value.errors == [ErrorItem(message=err, instance_path='')]
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'expected'],
+    [
+        (int, 1, 1),
+        (float, 1.5, 1.5),
+        (bool, False, False),
+        (Decimal, 1, Decimal('1')),
+        (Decimal, 1.5, Decimal('1.5')),
+        (Decimal, Decimal('1.5'), Decimal('1.5')),
+    ],
+)
+def test_strict_mode(t, value, expected):
+    assert Serializer(t, coercion_mode=CoercionMode.strict).load(value) == expected
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'err'],
+    [
+        (int, True, 'True is not of type "integer"'),
+        (int, '1', '"1" is not of type "integer"'),
+        (float, 1, '1 is not of type "number"'),
+        (float, True, 'True is not of type "number"'),
+        (Decimal, '1.5', '"1.5" is not of type "decimal"'),
+        (Decimal, True, 'True is not of type "decimal"'),
+    ],
+)
+def test_strict_mode__invalid(t, value, err):
+    with pytest.raises(SchemaValidationError) as e:
+        Serializer(t, coercion_mode=CoercionMode.strict).load(value)
+    assert e.value.errors == [ErrorItem(message=err, instance_path='')]
+
+
+def test_strict_mode__json_numbers():
+    assert Serializer(float, coercion_mode=CoercionMode.strict).load_json('1') == 1
+    assert Serializer(Decimal, coercion_mode=CoercionMode.strict).load_json('1.10') == Decimal('1.10')
+    with pytest.raises(SchemaValidationError):
+        Serializer(Decimal, coercion_mode=CoercionMode.strict).load_json('"1.10"')
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'expected'],
+    [
+        (int, '1', 1),
+        (int, str(2**70), 2**70),
+        (int, 2.0, 2),
+        (float, '1.5', 1.5),
+        (float, 1, 1),
+        (bool, 0, False),
+        (bool, 1, True),
+        (bool, 'true', True),
+        (bool, 'F', False),
+        (Decimal, '1.5', Decimal('1.5')),
+        (Decimal, False, Decimal('0')),
+    ],
+)
+def test_lax_mode(t, value, expected):
+    result = Serializer(t, coercion_mode=CoercionMode.lax).load(value)
+    assert result == expected
+    assert type(result) is type(expected)
+
+
+@pytest.mark.parametrize(
+    ['t', 'value', 'err'],
+    [
+        (int, 1.5, '1.5 is not of type "integer"'),
+        (int, 'foo', '"foo" is not of type "integer"'),
+        (bool, 2, '2 is not of type "boolean"'),
+        (str, 1, '1 is not of type "string"'),
+    ],
+)
+def test_lax_mode__invalid(t, value, err):
+    with pytest.raises(SchemaValidationError) as e:
+        Serializer(t, coercion_mode=CoercionMode.lax).load(value)
+    assert e.value.errors == [ErrorItem(message=err, instance_path='')]
+
+
+def test_mode_per_field():
+    @dataclass
+    class Inner:
+        a: int
+
+    @dataclass
+    class A:
+        strict: Annotated[float, Strict]
+        lax: Annotated[list[Inner], Lax]
+        default: int
+
+    serializer = Serializer(A)
+    assert serializer.load({'strict': 1.5, 'lax': [{'a': '1'}], 'default': 2}) == A(
+        strict=1.5, lax=[Inner(a=1)], default=2
+    )
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'strict': 1, 'lax': [], 'default': '2'})
+    assert e.value.errors == [ErrorItem(message='1 is not of type "number"', instance_path='strict')]
+
+
+def test_mode_per_field__overrides_serializer_mode():
+    @dataclass
+    class A:
+        a: int
+        b: Annotated[int, DefaultCoercion]
+
+    serializer = Serializer(A, coercion_mode=CoercionMode.lax)
+    assert serializer.load({'a': '1', 'b': 2}) == A(a=1, b=2)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'a': '1', 'b': '2'})
+    assert e.value.errors == [ErrorItem(message='"2" is not of type "integer"', instance_path='b')]
+
+
+def test_strict_mode__query_params_are_parsed():
+    @dataclass
+    class A:
+        a: int
+        b: float
+
+    serializer: Serializer[Any] = Serializer(A, coercion_mode=CoercionMode.strict)
+    assert serializer.load_query_params(MultiDict({'a': '1', 'b': '1.5'})) == A(a=1, b=1.5)
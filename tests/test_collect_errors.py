@@ -0,0 +1,88 @@
+from dataclasses import dataclass
+from typing import Optional, TypedDict
+
+import pytest
+from serpyco_rs import ErrorItem, SchemaValidationError, Serializer
+
+
+@dataclass
+class Item:
+    id: int
+    name: str
+
+
+@dataclass
+class Form:
+    title: str
+    items: list[Item]
+    tags: dict[str, int]
+    point: tuple[int, int]
+    parent: Optional['Form'] = None
+
+
+INVALID = {
+    'items': [{'id': 1, 'name': 'ok'}, {'id': 'a'}],
+    'tags': {'a': 1, 'b': 'x'},
+    'point': [1, 'y'],
+    'parent': {'title': 1, 'items': [], 'tags': {}, 'point': [0, 0]},
+}
+
+EXPECTED_ERRORS = [
+    ErrorItem(message='"title" is a required property', instance_path='title'),
+    ErrorItem(message='"a" is not of type "integer"', instance_path='items/1/id'),
+    ErrorItem(message='"name" is a required property', instance_path='items/1/name'),
+    ErrorItem(message='"x" is not of type "integer"', instance_path='tags/b'),
+    ErrorItem(message='"y" is not of type "integer"', instance_path='point/1'),
+    ErrorItem(message='1 is not of type "string"', instance_path='parent/title'),
+]
+
+
+def test_collect_errors__load():
+    serializer = Serializer(Form, collect_errors=True)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load(INVALID)
+
+    assert e.value.errors == EXPECTED_ERRORS
+
+
+def test_collect_errors__load_json():
+    import json
+
+    serializer = Serializer(Form, collect_errors=True)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load_json(json.dumps(INVALID))
+
+    assert e.value.errors == EXPECTED_ERRORS
+
+
+def test_collect_errors__typed_dict():
+    class A(TypedDict):
+        foo: int
+        bar: str
+
+    serializer = Serializer(A, collect_errors=True)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'bar': 1})
+
+    assert e.value.errors == [
+        ErrorItem(message='"foo" is a required property', instance_path='foo'),
+        ErrorItem(message='1 is not of type "string"', instance_path='bar'),
+    ]
+
+
+def test_collect_errors__valid_data():
+    serializer = Serializer(Form, collect_errors=True)
+
+    assert serializer.load({'title': 'a', 'items': [], 'tags': {}, 'point': [1, 2]}) == Form(
+        title='a', items=[], tags={}, point=(1, 2)
+    )
+
+
+def test_collect_errors__disabled_by_default():
+    with pytest.raises(SchemaValidationError) as e:
+        Serializer(Form).load(INVALID)
+
+    assert e.value.errors == EXPECTED_ERRORS[:1]
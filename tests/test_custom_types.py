@@ -1,11 +1,12 @@
 from dataclasses import dataclass
 from ipaddress import IPv4Address, AddressValueError
-from typing import Optional
+from typing import Annotated, Any, Literal, Optional, Union
 
 import pytest
 
 from serpyco_rs import Serializer, SchemaValidationError, ErrorItem
 from serpyco_rs._custom_types import CustomType
+from serpyco_rs.metadata import Discriminator
 
 
 class IPv4AddressType(CustomType[IPv4Address, str]):
@@ -72,3 +73,41 @@ def test_custom_type__validation_error():
         ErrorItem(message="AddressValueError: Expected 4 octets in 'invalid'", instance_path='ip')
     ]
     assert isinstance(exc_info.value.__cause__, AddressValueError)
+
+
+class Ping:
+    type: Literal['ping']
+
+    def __init__(self, payload: str):
+        self.type = 'ping'
+        self.payload = payload
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, Ping) and other.payload == self.payload
+
+
+class PingType(CustomType[Ping, dict[str, Any]]):
+    def serialize(self, value: Ping) -> dict[str, Any]:
+        return {'type': value.type, 'payload': value.payload}
+
+    def deserialize(self, value: dict[str, Any]) -> Ping:
+        return Ping(value['payload'])
+
+    def get_json_schema(self):
+        return {'type': 'object'}
+
+
+def test_custom_type__discriminated_union_variant():
+    @dataclass
+    class Pong:
+        type: Literal['pong']
+
+    serializer = Serializer(
+        Annotated[Union[Ping, Pong], Discriminator('type')],
+        custom_type_resolver=lambda t: PingType() if t is Ping else None,
+    )
+
+    assert serializer.load({'type': 'ping', 'payload': 'x'}) == Ping('x')
+    assert serializer.load({'type': 'pong'}) == Pong(type='pong')
+    assert serializer.dump(Ping('x')) == {'type': 'ping', 'payload': 'x'}
+    assert serializer.get_json_schema()['oneOf'][0] == {'type': 'object'}
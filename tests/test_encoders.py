@@ -5,12 +5,26 @@ from dataclasses import dataclass
 from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from enum import Enum, IntEnum
-from typing import Generic, Literal, TypeVar
+from typing import Annotated, Any, Generic, Literal, TypeVar, Union
 from zoneinfo import ZoneInfo
 
 import pytest
 from dateutil.tz import tzoffset
-from serpyco_rs import Serializer, ValidationError
+from serpyco_rs import ErrorItem, SchemaValidationError, Serializer, ValidationError
+from serpyco_rs.metadata import (
+    AssumeTimezone,
+    ConvertToUTC,
+    DateFormat,
+    DecimalAsNumber,
+    DecimalPlaces,
+    IsoDuration,
+    RequireAware,
+    RequireNaive,
+    SortedItems,
+    Timestamp,
+    TotalSeconds,
+    TotalSecondsInt,
+)
 from typing_extensions import TypedDict
 
 
@@ -36,6 +50,36 @@ def test_decimal():
     assert serializer.load('123.1') == Decimal('123.1')
 
 
+def test_decimal__dump_as_number():
+    serializer = Serializer(Annotated[Decimal, DecimalAsNumber])
+    assert serializer.dump(Decimal('0.10000000000000000000001')) == Decimal('0.10000000000000000000001')
+    assert serializer.dump_json(Decimal('0.10000000000000000000001')) == b'0.10000000000000000000001'
+    assert serializer.dump_json(Decimal('1E+3')) == b'1E+3'
+    assert serializer.dump_json(Decimal('NaN')) == b'null'
+    assert serializer.load_json(b'0.10000000000000000000001') == Decimal('0.10000000000000000000001')
+
+
+@pytest.mark.parametrize(
+    ['rounding', 'value', 'expected'],
+    [
+        ('ROUND_HALF_UP', '1.005', Decimal('1.01')),
+        ('ROUND_HALF_EVEN', '1.005', Decimal('1.00')),
+        ('ROUND_DOWN', 1.999, Decimal('1.99')),
+        ('ROUND_HALF_UP', 1, Decimal('1.00')),
+    ],
+)
+def test_decimal__rounding(rounding, value, expected):
+    serializer = Serializer(Annotated[Decimal, DecimalPlaces(2, rounding=rounding)])
+    loaded = serializer.load(value)
+    assert loaded == expected
+    assert str(loaded) == str(expected)
+
+
+def test_decimal__unknown_rounding():
+    with pytest.raises(ValueError, match='Unknown rounding mode: "ROUND_RANDOM"'):
+        Serializer(Annotated[Decimal, DecimalPlaces(2, rounding='ROUND_RANDOM')])  # type: ignore[arg-type]
+
+
 def test_decimal_invalid_value__raise_validation_error():
     serializer = Serializer(Decimal)
 
@@ -99,6 +143,259 @@ def test_tuple():
     assert serializer.load([1, True, 's']) == (1, True, 's')
 
 
+def test_variable_length_tuple():
+    serializer = Serializer(tuple[int, ...])
+    assert serializer.dump((1, 2, 3)) == [1, 2, 3]
+    assert serializer.load([1, 2, 3]) == (1, 2, 3)
+    assert serializer.load(()) == ()
+    assert serializer.dump_json((1, 2)) == b'[1,2]'
+    assert serializer.load_json('[1,2]') == (1, 2)
+
+
+@pytest.mark.parametrize(
+    ['value', 'expected'],
+    [
+        (timedelta(days=1, hours=2), 'P1DT2H'),
+        (timedelta(minutes=1, seconds=30), 'PT1M30S'),
+        (timedelta(seconds=1, microseconds=500), 'PT1.0005S'),
+        (timedelta(days=400), 'P400D'),
+        (timedelta(), 'PT0S'),
+        (-timedelta(days=1, seconds=1), '-P1DT1S'),
+    ],
+)
+def test_timedelta__iso(value, expected):
+    serializer = Serializer(timedelta)
+    assert serializer.dump(value) == expected
+    assert serializer.load(expected) == value
+    assert serializer.dump_json(value) == f'"{expected}"'.encode()
+
+
+@pytest.mark.parametrize(
+    ['annotation', 'value', 'expected'],
+    [
+        (TotalSeconds, timedelta(minutes=1, microseconds=500), 60.0005),
+        (TotalSeconds, -timedelta(seconds=1.5), -1.5),
+        (TotalSecondsInt, timedelta(hours=1), 3600),
+        (TotalSecondsInt, timedelta(seconds=1.9), 1),
+        (TotalSecondsInt, -timedelta(seconds=1.9), -1),
+    ],
+)
+def test_timedelta__total_seconds(annotation, value, expected):
+    serializer = Serializer(Annotated[timedelta, annotation])
+    dumped = serializer.dump(value)
+    assert dumped == expected
+    assert type(dumped) is type(expected)
+
+
+@pytest.mark.parametrize(
+    ['value', 'expected'],
+    [
+        ('P1DT2H', timedelta(days=1, hours=2)),
+        ('PT0.5S', timedelta(milliseconds=500)),
+        ('-PT30M', -timedelta(minutes=30)),
+        (90, timedelta(seconds=90)),
+        (-1.25, -timedelta(seconds=1.25)),
+        (0.000_000_4, timedelta()),
+    ],
+)
+def test_timedelta__load_accepts_iso_and_seconds(value, expected):
+    for annotation in (IsoDuration, TotalSeconds, TotalSecondsInt):
+        assert Serializer(Annotated[timedelta, annotation]).load(value) == expected
+
+
+@pytest.mark.parametrize(
+    ['timestamp', 'value', 'expected'],
+    [
+        (Timestamp(), datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc), 1704164645),
+        (Timestamp(unit='ms'), datetime(2024, 1, 2, 3, 4, 5, 678000, tzinfo=timezone.utc), 1704164645678),
+        (Timestamp(unit='us'), datetime(1969, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc), -1),
+        (Timestamp(unit='s', as_float=True), datetime(2024, 1, 2, 3, 4, 5, 500000, tzinfo=timezone.utc), 1704164645.5),
+    ],
+)
+def test_datetime_timestamp(timestamp, value, expected):
+    serializer = Serializer(Annotated[datetime, timestamp])
+    dumped = serializer.dump(value)
+    assert dumped == expected
+    assert type(dumped) is type(expected)
+    assert serializer.load(expected) == value
+    assert serializer.load(expected).tzinfo is timezone.utc
+    assert serializer.dump_json(value) == str(expected).encode()
+
+
+def test_datetime_timestamp__aware_and_naive_dump():
+    serializer = Serializer(Annotated[datetime, Timestamp(unit='ms')])
+    assert serializer.dump(datetime(2024, 1, 1, 3, tzinfo=timezone(timedelta(hours=3)))) == 1704067200000
+    # naive datetimes are treated as UTC
+    assert serializer.dump(datetime(2024, 1, 1)) == 1704067200000
+    assert serializer.dump(datetime(2024, 1, 1, 0, 0, 0, 1999, tzinfo=timezone.utc)) == 1704067200001
+
+
+def test_datetime_timestamp__load_float():
+    serializer = Serializer(Annotated[datetime, Timestamp(unit='ms')])
+    assert serializer.load(1704067200000.5) == datetime(2024, 1, 1, 0, 0, 0, 500, tzinfo=timezone.utc)
+
+
+
+@pytest.mark.parametrize(
+    ['timestamp', 'value', 'expected'],
+    [
+        (Timestamp(), date(2024, 1, 2), 1704153600),
+        (Timestamp(unit='ms'), date(1969, 12, 31), -86400000),
+        (Timestamp(as_float=True), date(2024, 1, 2), 1704153600.0),
+    ],
+)
+def test_date_timestamp(timestamp, value, expected):
+    serializer = Serializer(Annotated[date, timestamp])
+    dumped = serializer.dump(value)
+    assert dumped == expected
+    assert type(dumped) is type(expected)
+    assert serializer.load(expected) == value
+    assert serializer.dump_json(value) == str(expected).encode()
+
+def test_datetime_require_aware():
+    serializer = Serializer(Annotated[datetime, RequireAware])
+    value = datetime(2024, 1, 2, 3, 4, tzinfo=timezone(timedelta(hours=3)))
+    assert serializer.load('2024-01-02T03:04:00+03:00') == value
+    assert serializer.dump(value) == '2024-01-02T03:04:00+03:00'
+    with pytest.raises(ValueError, match='is not an aware datetime'):
+        serializer.dump(datetime(2024, 1, 2, 3, 4))
+
+
+def test_datetime_require_naive():
+    serializer = Serializer(Annotated[datetime, RequireNaive])
+    assert serializer.load('2024-01-02T03:04:00') == datetime(2024, 1, 2, 3, 4)
+    assert serializer.dump(datetime(2024, 1, 2, 3, 4)) == '2024-01-02T03:04:00'
+    with pytest.raises(ValueError, match='is not a naive datetime'):
+        serializer.dump_json(datetime(2024, 1, 2, 3, 4, tzinfo=timezone.utc))
+
+
+def test_datetime_convert_to_utc():
+    serializer = Serializer(Annotated[datetime, ConvertToUTC])
+    loaded = serializer.load('2024-01-02T03:04:00+03:00')
+    assert loaded == datetime(2024, 1, 2, 0, 4, tzinfo=timezone.utc)
+    assert loaded.utcoffset() == timedelta(0)
+    # naive datetimes are treated as UTC
+    assert serializer.load('2024-01-02T03:04:00') == datetime(2024, 1, 2, 3, 4, tzinfo=timezone.utc)
+    assert serializer.dump(datetime(2024, 1, 2, 3, 4, tzinfo=timezone(timedelta(hours=3)))) == '2024-01-02T00:04:00Z'
+    assert serializer.dump_json(datetime(2024, 1, 2, 3, 4)) == b'"2024-01-02T03:04:00Z"'
+
+
+def test_datetime_assume_timezone():
+    serializer = Serializer(Annotated[datetime, AssumeTimezone('Europe/Berlin')])
+    loaded = serializer.load('2024-07-02T03:04:00')
+    assert loaded.tzinfo == ZoneInfo('Europe/Berlin')
+    assert loaded.utcoffset() == timedelta(hours=2)
+    assert serializer.load('2024-07-02T03:04:00Z').tzinfo == timezone.utc
+    assert serializer.dump(datetime(2024, 1, 2, 3, 4)) == '2024-01-02T03:04:00+01:00'
+
+
+def test_datetime_assume_timezone__unknown_timezone():
+    with pytest.raises(ValueError, match='Unknown timezone: "Mars/Olympus"'):
+        Serializer(Annotated[datetime, AssumeTimezone('Mars/Olympus')])
+
+
+def test_datetime_tz_policy__timestamp():
+    serializer = Serializer(Annotated[datetime, Timestamp(), AssumeTimezone('Europe/Berlin')])
+    assert serializer.dump(datetime(2024, 1, 1, 1)) == 1704067200
+
+
+@pytest.mark.parametrize(
+    ['annotation', 'value', 'expected'],
+    [
+        (Annotated[date, DateFormat('%d.%m.%Y')], date(2024, 1, 2), '02.01.2024'),
+        (Annotated[time, DateFormat('%H-%M')], time(3, 4), '03-04'),
+        (Annotated[time, DateFormat('%H:%M:%S%.6f')], time(3, 4, 5, 123), '03:04:05.000123'),
+        (Annotated[datetime, DateFormat('%Y/%m/%d %H:%M')], datetime(2024, 1, 2, 3, 4), '2024/01/02 03:04'),
+        (
+            Annotated[datetime, DateFormat('%d.%m.%Y %H:%M %z')],
+            datetime(2024, 1, 2, 3, 4, tzinfo=timezone(timedelta(hours=3))),
+            '02.01.2024 03:04 +0300',
+        ),
+    ],
+)
+def test_date_format(annotation, value, expected):
+    serializer = Serializer(annotation)
+    assert serializer.dump(value) == expected
+    assert serializer.dump_json(value) == f'"{expected}"'.encode()
+    assert serializer.load(expected) == value
+    assert serializer.load_json(f'"{expected}"') == value
+
+
+def test_date_format__naive_datetime():
+    serializer = Serializer(Annotated[datetime, DateFormat('%Y-%m-%d %H:%M %z')], naive_datetime_to_utc=True)
+    assert serializer.dump(datetime(2024, 1, 2, 3, 4)) == '2024-01-02 03:04 +0000'
+
+    serializer = Serializer(Annotated[datetime, DateFormat('%Y-%m-%d %H:%M %z')])
+    with pytest.raises(ValueError, match='requires an aware datetime'):
+        serializer.dump(datetime(2024, 1, 2, 3, 4))
+
+
+def test_date_format__invalid_format():
+    with pytest.raises(ValueError, match='Invalid date format'):
+        Serializer(Annotated[date, DateFormat('%Q')])
+
+
+def test_set():
+    serializer = Serializer(set[int])
+    assert sorted(serializer.dump({3, 1, 2})) == [1, 2, 3]
+    assert serializer.load([1, 2, 2, 3]) == {1, 2, 3}
+    assert serializer.load_json('[3,1,1]') == {1, 3}
+
+
+def test_frozenset():
+    serializer = Serializer(frozenset[str])
+    loaded = serializer.load(['a', 'b'])
+    assert isinstance(loaded, frozenset)
+    assert loaded == frozenset({'a', 'b'})
+    assert sorted(serializer.dump(frozenset({'a', 'b'}))) == ['a', 'b']
+
+
+def test_set__sorted_items__dumped_sorted():
+    serializer = Serializer(Annotated[set[str], SortedItems])
+    value = {'c', 'a', 'b', 'e', 'd'}
+    assert serializer.dump(value) == ['a', 'b', 'c', 'd', 'e']
+    assert serializer.dump_json(value) == b'["a","b","c","d","e"]'
+
+
+def test_set__sorted_items__sorted_by_dumped_values():
+    class Color(Enum):
+        red = 'r'
+        green = 'g'
+        blue = 'b'
+
+    serializer = Serializer(Annotated[frozenset[Color], SortedItems])
+    assert serializer.dump(frozenset(Color)) == ['b', 'g', 'r']
+
+
+
+def test_set__sorted_items__mixed_types():
+    serializer = Serializer(Annotated[set[Union[int, str]], SortedItems])
+    value = {'b', 3, 'a', 1}
+    assert serializer.dump(value) == [1, 3, 'a', 'b']
+    assert serializer.dump_json(value) == b'[1,3,"a","b"]'
+
+
+def test_set__unhashable_item():
+    serializer = Serializer(set[Any])
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load([1, [1]])
+    assert exc_info.value.errors == [ErrorItem(message='[1] is not hashable', instance_path='1')]
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load_json('[1, [1]]')
+    assert exc_info.value.errors == [ErrorItem(message='[1] is not hashable', instance_path='1')]
+
+def test_variable_length_tuple__in_frozen_dataclass__hashable():
+    @dataclass(frozen=True)
+    class A:
+        tags: tuple[str, ...]
+
+    serializer = Serializer(A)
+    loaded = serializer.load({'tags': ['a', 'b']})
+    assert loaded == A(tags=('a', 'b'))
+    assert hash(loaded) == hash(A(tags=('a', 'b')))
+    assert serializer.dump(loaded) == {'tags': ['a', 'b']}
+
+
 @pytest.mark.parametrize(
     ['value', 'expected'],
     (
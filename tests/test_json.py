@@ -0,0 +1,279 @@
+import json
+import uuid
+from dataclasses import dataclass, field
+from datetime import date, datetime, time, timezone
+from decimal import Decimal
+from enum import Enum
+from typing import Annotated, Any, Literal, Optional, TypedDict, Union
+
+import pytest
+from serpyco_rs import SchemaValidationError, Serializer, ValidationError
+from serpyco_rs.metadata import CustomEncoder, Discriminator
+
+
+class Color(Enum):
+    red = 'red'
+    green = 'green'
+
+
+@dataclass
+class Nested:
+    value: int
+
+
+@dataclass
+class Full:
+    int_f: int
+    float_f: float
+    bool_f: bool
+    str_f: str
+    decimal_f: Decimal
+    uuid_f: uuid.UUID
+    datetime_f: datetime
+    date_f: date
+    time_f: time
+    enum_f: Color
+    literal_f: Literal['foo', 'bar']
+    list_f: list[Nested]
+    dict_f: dict[str, int]
+    tuple_f: tuple[int, str]
+    optional_f: Optional[int]
+    any_f: Any
+    union_f: Union[int, str]
+
+
+def test_dump_json__matches_dump():
+    serializer = Serializer(Full)
+    obj = Full(
+        int_f=1,
+        float_f=1.5,
+        bool_f=True,
+        str_f='юникод "quoted"\n',
+        decimal_f=Decimal('0.1'),
+        uuid_f=uuid.UUID('a8098c1a-f86e-11da-bd1a-00112444be1e'),
+        datetime_f=datetime(2024, 1, 1, 10, 20, 30, tzinfo=timezone.utc),
+        date_f=date(2024, 1, 1),
+        time_f=time(10, 20, 30),
+        enum_f=Color.green,
+        literal_f='bar',
+        list_f=[Nested(value=1), Nested(value=2)],
+        dict_f={'a': 1},
+        tuple_f=(1, 'a'),
+        optional_f=None,
+        any_f={'foo': [1, 2.5, None, True]},
+        union_f='foo',
+    )
+
+    result = serializer.dump_json(obj)
+
+    assert isinstance(result, bytes)
+    assert json.loads(result) == serializer.dump(obj)
+
+
+@pytest.mark.parametrize(
+    ['value', 'expected'],
+    [
+        (2**64, b'18446744073709551616'),
+        (-1, b'-1'),
+        (1.0, b'1.0'),
+        (float('nan'), b'null'),
+    ],
+)
+def test_dump_json__numbers(value, expected):
+    assert Serializer(Any).dump_json(value) == expected
+
+
+def test_dump_json__omit_none():
+    @dataclass
+    class A:
+        required: Optional[int]
+        optional: Optional[int] = None
+        mapping: dict[str, Optional[int]] = field(default_factory=dict)
+
+    serializer = Serializer(A, omit_none=True)
+
+    assert serializer.dump_json(A(required=None, mapping={'a': None, 'b': 1})) == b'{"required":null,"mapping":{"b":1}}'
+
+
+def test_dump_json__typed_dict():
+    class A(TypedDict, total=False):
+        foo: int
+        bar: str
+
+    assert Serializer(A).dump_json({'foo': 1}) == b'{"foo":1}'
+
+
+def test_dump_json__dict_keys_coercion():
+    serializer = Serializer(dict[int, int])
+    assert serializer.dump_json({1: 2}) == b'{"1":2}'
+
+
+def test_dump_json__custom_encoder():
+    serializer = Serializer(Annotated[int, CustomEncoder[int, str](serialize=str)])
+    assert serializer.dump_json(1) == b'"1"'
+
+
+def test_dump_json__tagged_union():
+    @dataclass
+    class Foo:
+        type: Literal['foo']
+        value: int
+
+    @dataclass
+    class Bar:
+        type: Literal['bar']
+        value: str
+
+    serializer = Serializer(list[Annotated[Union[Foo, Bar], Discriminator('type')]])
+
+    assert (
+        serializer.dump_json([Foo(type='foo', value=1), Bar(type='bar', value='a')])
+        == b'[{"type":"foo","value":1},{"type":"bar","value":"a"}]'
+    )
+
+
+def test_dump_json__union_of_entities():
+    @dataclass
+    class Foo:
+        foo: int
+
+    @dataclass
+    class Bar:
+        bar: int
+
+    serializer = Serializer(list[Union[Foo, Bar]])
+
+    assert serializer.dump_json([Foo(foo=1), Bar(bar=2)]) == b'[{"foo":1},{"bar":2}]'
+
+
+@dataclass
+class Node:
+    value: int
+    next: Optional['Node'] = None
+
+
+def test_dump_json__recursive():
+    serializer = Serializer(Node)
+
+    assert serializer.dump_json(Node(1, Node(2))) == b'{"value":1,"next":{"value":2,"next":null}}'
+
+
+def test_dump_json__not_serializable():
+    serializer = Serializer(Any)
+    with pytest.raises(TypeError, match='Object of type set is not JSON serializable'):
+        serializer.dump_json({1})
+
+
+def test_load_json__matches_load():
+    serializer = Serializer(Full)
+    data = {
+        'int_f': 1,
+        'float_f': 1.5,
+        'bool_f': True,
+        'str_f': 'юникод "quoted"\n',
+        'decimal_f': '0.1',
+        'uuid_f': 'a8098c1a-f86e-11da-bd1a-00112444be1e',
+        'datetime_f': '2024-01-01T10:20:30Z',
+        'date_f': '2024-01-01',
+        'time_f': '10:20:30',
+        'enum_f': 'green',
+        'literal_f': 'bar',
+        'list_f': [{'value': 1}, {'value': 2}],
+        'dict_f': {'a': 1},
+        'tuple_f': [1, 'a'],
+        'optional_f': None,
+        'any_f': {'foo': [1, 2.5, None, True]},
+        'union_f': 'foo',
+    }
+
+    assert serializer.load_json(json.dumps(data)) == serializer.load(data)
+    assert serializer.load_json(json.dumps(data).encode()) == serializer.load(data)
+
+
+@pytest.mark.parametrize(
+    ['data', 'expected'],
+    [
+        (b'18446744073709551616', 2**64),
+        (b'-1', -1),
+        (b'1.5', 1.5),
+        (b'1e400', float('inf')),
+        (b'[1, {"a": null}]', [1, {'a': None}]),
+    ],
+)
+def test_load_json__any(data, expected):
+    assert Serializer(Any).load_json(data) == expected
+
+
+def test_load_json__decimal_keeps_precision():
+    assert Serializer(Decimal).load_json(b'0.10000000000000000000001') == Decimal('0.10000000000000000000001')
+
+
+def test_load_json__defaults():
+    @dataclass
+    class A:
+        a: int = 1
+        b: list[int] = field(default_factory=list)
+
+    assert Serializer(A).load_json(b'{}') == A()
+
+
+def test_load_json__typed_dict():
+    class A(TypedDict, total=False):
+        foo: int
+        bar: str
+
+    assert Serializer(A).load_json(b'{"foo": 1}') == {'foo': 1}
+
+
+def test_load_json__tagged_union():
+    @dataclass
+    class Foo:
+        type: Literal['foo']
+        value: int
+
+    @dataclass
+    class Bar:
+        type: Literal['bar']
+        value: str
+
+    serializer = Serializer(list[Annotated[Union[Foo, Bar], Discriminator('type')]])
+
+    assert serializer.load_json(b'[{"type": "foo", "value": 1}, {"type": "bar", "value": "a"}]') == [
+        Foo(type='foo', value=1),
+        Bar(type='bar', value='a'),
+    ]
+
+
+def test_load_json__recursive():
+    assert Serializer(Node).load_json(b'{"value": 1, "next": {"value": 2}}') == Node(1, Node(2))
+
+
+@pytest.mark.parametrize(
+    ['data', 'message', 'instance_path'],
+    [
+        (b'{"value": 1, "next": {}}', '"value" is a required property', 'next/value'),
+        (b'{"value": 1, "next": {"value": "a"}}', '"a" is not of type "integer"', 'next/value'),
+        (b'[]', '[] is not of type "object"', ''),
+    ],
+)
+def test_load_json__validation_error(data, message, instance_path):
+    serializer = Serializer(Node)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load_json(data)
+
+    with pytest.raises(SchemaValidationError) as expected:
+        serializer.load(json.loads(data))
+
+    assert e.value.errors[0].message == expected.value.errors[0].message == message
+    assert e.value.errors[0].instance_path == expected.value.errors[0].instance_path == instance_path
+
+
+def test_load_json__invalid_json():
+    with pytest.raises(ValidationError, match='Invalid JSON'):
+        Serializer(int).load_json(b'{')
+
+
+def test_load_json__invalid_input_type():
+    with pytest.raises(TypeError, match='Expected bytes or str, got int'):
+        Serializer(int).load_json(1)
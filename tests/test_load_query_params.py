@@ -28,6 +28,17 @@ def test_load_query_params__int():
     assert serializer.load_query_params(MultiDict({'id': '1'})) == Foo(id=1)
 
 
+def test_load_query_params__big_int():
+    @dataclass
+    class Foo:
+        id: Annotated[int, metadata.Min(0)]
+
+    serializer = serpyco_rs.Serializer(Foo)
+    assert serializer.load_query_params(MultiDict({'id': str(2**128 - 1)})) == Foo(id=2**128 - 1)
+    with pytest.raises(serpyco_rs.SchemaValidationError):
+        serializer.load_query_params(MultiDict({'id': str(-(2**100))}))
+
+
 def test_load_query_params__int__empty():
     @dataclass
     class Foo:
@@ -38,14 +49,25 @@ def test_load_query_params__int__empty():
         serializer.load_query_params(MultiDict())
 
 
-def test_load_query_params__int__invalid():
+@pytest.mark.parametrize('value', ['1.1', '1_000', '', '-', ' 1'])
+def test_load_query_params__int__invalid(value):
     @dataclass
     class Foo:
         id: int
 
     serializer = serpyco_rs.Serializer(Foo)
     with pytest.raises(serpyco_rs.SchemaValidationError):
-        serializer.load_query_params(MultiDict({'id': '1.1'}))
+        serializer.load_query_params(MultiDict({'id': value}))
+
+
+def test_load_query_params__int__signed():
+    @dataclass
+    class Foo:
+        id: int
+
+    serializer = serpyco_rs.Serializer(Foo)
+    assert serializer.load_query_params(MultiDict({'id': '+1'})) == Foo(id=1)
+    assert serializer.load_query_params(MultiDict({'id': '-1'})) == Foo(id=-1)
 
 
 def test_load_query_params__float():
@@ -227,3 +249,33 @@ def test_load_query_params__dict_of_lists():
         'foo': [1, 2],
         'bar': [3],
     }
+
+
+def test_load_query_params__forbid_extra():
+    @dataclass
+    class Foo:
+        x: int
+
+    serializer = serpyco_rs.Serializer(Foo, forbid_extra=True)
+    assert serializer.load_query_params(MultiDict({'x': '1'})) == Foo(x=1)
+    with pytest.raises(serpyco_rs.SchemaValidationError) as exc_info:
+        serializer.load_query_params(MultiDict({'x': '1', 'y': '2'}))
+    assert exc_info.value.errors == [
+        serpyco_rs.ErrorItem(message='Additional property "y" is not allowed', instance_path='y'),
+    ]
+
+
+def test_load_query_params__extra_fields():
+    @dataclass
+    class Foo:
+        x: int
+        extra: Annotated[dict[str, int], metadata.ExtraFields]
+
+    @dataclass
+    class Bar:
+        x: int
+        extra: Annotated[dict[str, list[int]], metadata.ExtraFields]
+
+    data = MultiDict([('x', '1'), ('y', '2'), ('y', '3')])
+    assert serpyco_rs.Serializer(Foo).load_query_params(data) == Foo(x=1, extra={'y': 2})
+    assert serpyco_rs.Serializer(Bar).load_query_params(data) == Bar(x=1, extra={'y': [2, 3]})
@@ -1,9 +1,23 @@
-from dataclasses import dataclass
-from typing import Annotated, Optional
+import json
+from dataclasses import InitVar, dataclass, field
+from typing import Annotated, Any, Optional, TypedDict
 
+import attr
 import pytest
-from serpyco_rs import SchemaValidationError, Serializer
-from serpyco_rs.metadata import Alias, ForceDefaultForOptional, OmitNone
+from serpyco_rs import ErrorItem, SchemaValidationError, Serializer
+from serpyco_rs.metadata import (
+    Alias,
+    CallConstructor,
+    CallPostInit,
+    ExtraFields,
+    ForbidExtra,
+    ForceDefaultForOptional,
+    IgnoreExtra,
+    InitMode,
+    OmitNone,
+    ReadOnly,
+    WriteOnly,
+)
 
 
 def test_annotated_filed_alias():
@@ -99,3 +113,263 @@ def test_force_default_for_optional__override_by_annotated():
 
     # bar is annotated, and A.val is nullable+non required
     assert serializer.load({'foo': None, 'bar': {}}) == B(foo=None, bar=A(val=None))
+
+
+def test_forbid_extra():
+    @dataclass
+    class A:
+        foo: Annotated[int, Alias('bar')]
+
+    serializer = Serializer(Annotated[A, ForbidExtra])
+
+    assert serializer.load({'bar': 1}) == A(foo=1)
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'bar': 1, 'foo': 2})
+    assert e.value.errors == [ErrorItem(message='Additional property "foo" is not allowed', instance_path='foo')]
+
+
+def test_forbid_extra__typed_dict():
+    class A(TypedDict):
+        foo: int
+
+    serializer = Serializer(A, forbid_extra=True)
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load_json(b'{"foo": 1, "fooo": 2}')
+    assert e.value.errors == [ErrorItem(message='Additional property "fooo" is not allowed', instance_path='fooo')]
+
+
+def test_forbid_extra__propagate_to_nested():
+    @dataclass
+    class A:
+        foo: int
+
+    @dataclass
+    class B:
+        items: list[A]
+        other: Annotated[A, IgnoreExtra]
+
+    serializer = Serializer(B, forbid_extra=True, collect_errors=True)
+
+    assert serializer.load({'items': [], 'other': {'foo': 1, 'bar': 2}}) == B(items=[], other=A(foo=1))
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'items': [{'foo': 1, 'a': 1, 'b': 2}], 'other': {'foo': 1}, 'c': 3})
+    assert e.value.errors == [
+        ErrorItem(message='Additional property "a" is not allowed', instance_path='items/0/a'),
+        ErrorItem(message='Additional property "b" is not allowed', instance_path='items/0/b'),
+        ErrorItem(message='Additional property "c" is not allowed', instance_path='c'),
+    ]
+
+
+def test_extra_fields():
+    @dataclass
+    class A:
+        foo: int
+        extra: Annotated[dict[str, Any], ExtraFields]
+
+    serializer = Serializer(A, forbid_extra=True)
+    data = {'foo': 1, 'bar': [1], 'baz': None}
+    obj = A(foo=1, extra={'bar': [1], 'baz': None})
+
+    assert serializer.load(data) == obj
+    assert serializer.load_json(b'{"foo": 1, "bar": [1], "baz": null}') == obj
+    assert serializer.dump(obj) == data
+    assert serializer.dump_json(obj) == b'{"foo":1,"bar":[1],"baz":null}'
+    assert serializer.load({'foo': 1}) == A(foo=1, extra={})
+    assert serializer.dump(A(foo=1, extra={'foo': 2})) == {'foo': 1}
+
+
+def test_extra_fields__typed_dict_validation():
+    class A(TypedDict):
+        foo: int
+        extra: Annotated[dict[str, int], ExtraFields]
+
+    serializer = Serializer(A)
+
+    assert serializer.load({'foo': 1, 'bar': 2}) == {'foo': 1, 'extra': {'bar': 2}}
+    assert serializer.dump({'foo': 1, 'extra': {'bar': 2}}) == {'foo': 1, 'bar': 2}
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'foo': 1, 'bar': 'a'})
+    assert e.value.errors == [ErrorItem(message='"a" is not of type "integer"', instance_path='bar')]
+
+
+def test_extra_fields__invalid_type():
+    @dataclass
+    class A:
+        extra: Annotated[list[str], ExtraFields]
+
+    with pytest.raises(RuntimeError, match='ExtraFields field must be dict'):
+        Serializer(A)
+
+
+@dataclass
+class WithPostInit:
+    a: int
+    b: int
+
+    def __post_init__(self):
+        if self.a > self.b:
+            raise ValueError('a must be less than b')
+        self.total = self.a + self.b
+
+
+def test_read_only_write_only():
+    @dataclass
+    class User:
+        name: str
+        password: Annotated[str, WriteOnly]
+        id: Annotated[Optional[int], ReadOnly] = None
+        tags: Annotated[list[str], ReadOnly] = field(default_factory=list)
+
+    serializer = Serializer(User)
+    obj = User(name='foo', password='secret', id=1, tags=['a'])
+
+    assert serializer.dump(obj) == {'name': 'foo', 'id': 1, 'tags': ['a']}
+    assert serializer.dump_json(obj) == b'{"name":"foo","id":1,"tags":["a"]}'
+    data = {'name': 'foo', 'password': 'secret', 'id': 'not an int', 'tags': ['a']}
+    assert serializer.load(data) == User(name='foo', password='secret')
+    assert serializer.load_json(b'{"name": "foo", "password": "secret", "id": 1}') == User(
+        name='foo', password='secret'
+    )
+
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'name': 'foo'})
+    assert e.value.errors == [ErrorItem(message='"password" is a required property', instance_path='password')]
+
+
+def test_read_only__forbid_extra():
+    @dataclass
+    class A:
+        foo: int
+        id: Annotated[int, ReadOnly] = 0
+
+    serializer = Serializer(A, forbid_extra=True, collect_errors=True)
+
+    assert serializer.load({'foo': 1}) == A(foo=1)
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'foo': 1, 'id': 2, 'bar': 3})
+    assert e.value.errors == [
+        ErrorItem(message='Property "id" is read-only', instance_path='id'),
+        ErrorItem(message='Additional property "bar" is not allowed', instance_path='bar'),
+    ]
+    for load in (serializer.load, lambda v: serializer.load_json(json.dumps(v))):
+        with pytest.raises(SchemaValidationError) as e:
+            load({'foo': 1, 'id': 2})
+        assert e.value.errors == [ErrorItem(message='Property "id" is read-only', instance_path='id')]
+
+
+def test_read_only_write_only__typed_dict():
+    class A(TypedDict):
+        id: Annotated[int, ReadOnly]
+        password: Annotated[str, WriteOnly]
+
+    serializer = Serializer(A)
+
+    assert serializer.load({'id': 1, 'password': 'secret'}) == {'password': 'secret'}
+    assert serializer.load_json(b'{"id": 1, "password": "secret"}') == {'password': 'secret'}
+    assert serializer.dump({'id': 1, 'password': 'secret'}) == {'id': 1}
+    assert serializer.dump_json({'id': 1, 'password': 'secret'}) == b'{"id":1}'
+
+
+def test_read_only__requires_default():
+    @dataclass
+    class A:
+        id: Annotated[int, ReadOnly]
+
+    with pytest.raises(RuntimeError, match='ReadOnly field must have a default value'):
+        Serializer(A)
+
+
+def test_entity_init__skip_by_default():
+    obj = Serializer(WithPostInit).load({'a': 2, 'b': 1})
+    assert not hasattr(obj, 'total')
+
+
+@pytest.mark.parametrize('init', [CallPostInit, CallConstructor])
+def test_entity_init(init):
+    @dataclass
+    class A:
+        items: list[Annotated[WithPostInit, init]]
+
+    serializer = Serializer(A)
+
+    assert serializer.load({'items': [{'a': 1, 'b': 2}]}).items[0].total == 3
+    with pytest.raises(SchemaValidationError) as e:
+        serializer.load({'items': [{'a': 1, 'b': 2}, {'a': 2, 'b': 1}]})
+    assert e.value.errors == [ErrorItem(message='ValueError: a must be less than b', instance_path='items/1')]
+    assert isinstance(e.value.__cause__, ValueError)
+
+
+@pytest.mark.parametrize('init_mode', [InitMode.post_init, InitMode.constructor])
+def test_entity_init__serializer_option(init_mode):
+    @dataclass(frozen=True)
+    class A:
+        value: int
+        nested: Optional[WithPostInit] = None
+
+        def __post_init__(self):
+            object.__setattr__(self, 'double', self.value * 2)
+
+    serializer = Serializer(A, init_mode=init_mode)
+
+    obj = serializer.load_json(b'{"value": 1, "nested": {"a": 1, "b": 1}}')
+    assert obj.double == 2
+    assert obj.nested.total == 2
+
+
+def test_entity_init__constructor__not_init_fields():
+    @dataclass
+    class A:
+        x: int
+        y: int = field(init=False, default=0)
+
+    @attr.define
+    class B:
+        _x: int
+        y: int = attr.field(init=False, default=0)
+
+    obj = Serializer(A, init_mode=InitMode.constructor).load({'x': 1, 'y': 2})
+    assert (obj.x, obj.y) == (1, 2)
+    obj = Serializer(B, init_mode=InitMode.constructor).load({'_x': 1, 'y': 2})
+    assert (obj._x, obj.y) == (1, 2)
+
+
+@pytest.mark.parametrize('init_mode', [InitMode.post_init, InitMode.constructor])
+def test_entity_init__init_var(init_mode):
+    @dataclass
+    class A:
+        value: int
+        k: InitVar[int] = 2
+
+        def __post_init__(self, k: int):
+            self.scaled = self.value * k
+
+    assert Serializer(A, init_mode=init_mode).load({'value': 2}).scaled == 4
+
+
+def test_entity_init__init_var_without_default():
+    @dataclass
+    class A:
+        value: int
+        k: InitVar[int]
+
+        def __post_init__(self, k: int):
+            pass
+
+    with pytest.raises(RuntimeError, match='InitVar field must have a default value'):
+        Serializer(A, init_mode=InitMode.post_init)
+
+
+def test_entity_init__nested_validation_error_is_kept():
+    inner = Serializer(WithPostInit)
+
+    @dataclass
+    class A:
+        raw: dict[str, int]
+
+        def __post_init__(self):
+            self.inner = inner.load(self.raw)
+
+    with pytest.raises(SchemaValidationError) as e:
+        Serializer(A, init_mode=InitMode.post_init).load({'raw': {'a': 1}})
+    assert e.value.errors == [ErrorItem(message='"b" is a required property', instance_path='b')]
@@ -1,12 +1,14 @@
+import json
 import sys
 from dataclasses import dataclass
-from typing import Annotated, Literal, Optional, Union
-from enum import Enum
+from decimal import Decimal
+from typing import Annotated, Any, Literal, Optional, TypedDict, Union
+from enum import Enum, IntEnum
 
 import pytest
 
 from serpyco_rs import Serializer, SchemaValidationError, ErrorItem
-from serpyco_rs.metadata import Discriminator
+from serpyco_rs.metadata import CallPostInit, Discriminator, Lax, SmartUnion, Tag
 
 
 @dataclass
@@ -61,7 +63,7 @@ def test_tagged_union__invalid_discriminator_type():
     assert exc_info.type is RuntimeError
     assert exc_info.value.args[0] == (
         'Type <class \'tests.test_union.Buz\'> has invalid discriminator field "type" with type "<class \'int\'>". '
-        'Discriminator supports Literal[<str>], Literal[Enum] with str values.'
+        'Discriminator supports Literal[<str>], Literal[<int>], Literal[<bool>], Literal[Enum] with str or int values.'
     )
 
 
@@ -87,7 +89,8 @@ def test_tagged_union__unsupported_types():
 
     assert exc_info.type is RuntimeError
     assert exc_info.value.args[0] == (
-        "Unions supported only for dataclasses or attrs. Provided: typing.Union[<class 'int'>,<class 'str'>]"
+        'Unions supported only for dataclasses, attrs, TypedDict or custom types. '
+        "Provided: typing.Union[<class 'int'>,<class 'str'>]"
     )
 
 
@@ -229,6 +232,321 @@ def test_tagged_union__enum_tag():
     assert serializer.load(raw_obj) == obj
 
 
+def test_tagged_union__fallback_variant():
+    @dataclass
+    class Unknown:
+        type: str
+
+    serializer = Serializer(Annotated[Union[Foo, Bar, Unknown], Discriminator('type', fallback=Unknown)])
+
+    assert serializer.load({'type': 'foo', 'val': 1}) == Foo(val=1)
+    assert serializer.load({'type': 'new', 'val': 1}) == Unknown(type='new')
+    assert serializer.load_json('{"type": "new", "val": 1}') == Unknown(type='new')
+    assert serializer.dump(Unknown(type='new')) == {'type': 'new'}
+    assert serializer.dump_json(Unknown(type='new')) == b'{"type":"new"}'
+    assert serializer.dump(Foo(val=1)) == {'type': 'foo', 'val': 1}
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load({'val': 1})
+    assert exc_info.value.errors == [ErrorItem(message='"type" is a required property', instance_path='type')]
+
+
+def test_tagged_union__fallback_raw_dict():
+    serializer = Serializer(Annotated[Union[Foo, Bar], Discriminator('type', fallback=dict[str, Any])])
+
+    raw = {'type': 'new', 'nested': {'a': [1]}}
+    assert serializer.load(raw) == raw
+    assert serializer.load_json('{"type": "new", "nested": {"a": [1]}}') == raw
+    assert serializer.load({'type': 1}) == {'type': 1}
+    assert serializer.dump(raw) == raw
+    assert serializer.load({'type': 'bar', 'val': 'x'}) == Bar(type='bar', val='x')
+
+
+@pytest.mark.parametrize('load', ['load', 'load_json'])
+def test_tagged_union__default_tag(load):
+    serializer = Serializer(Annotated[Union[Foo, Bar], Discriminator('type', default_tag='bar')])
+    raw = {'val': 'x'}
+
+    loaded = serializer.load(raw) if load == 'load' else serializer.load_json('{"val": "x"}')
+
+    assert loaded == Bar(type='bar', val='x')
+    assert serializer.load({'type': 'foo', 'val': 1}) == Foo(val=1)
+    assert raw == {'val': 'x'}
+
+
+def test_tagged_union__invalid_default_tag():
+    with pytest.raises(RuntimeError) as exc_info:
+        Serializer(Annotated[Union[Foo, Bar], Discriminator('type', default_tag='new')])
+
+    assert exc_info.value.args[0].startswith('Default discriminator value "new" does not match any type of')
+
+
+class FooDict(TypedDict):
+    type: Literal['foo']
+    val: int
+
+
+class BarDict(TypedDict):
+    type: Literal['bar']
+    val: str
+
+
+def test_tagged_union__typed_dict():
+    serializer = Serializer(Annotated[Union[FooDict, BarDict], Discriminator('type')])
+
+    assert serializer.load({'type': 'foo', 'val': 1}) == {'type': 'foo', 'val': 1}
+    assert serializer.load_json('{"type": "bar", "val": "x"}') == {'type': 'bar', 'val': 'x'}
+    assert serializer.dump({'type': 'bar', 'val': 'x'}) == {'type': 'bar', 'val': 'x'}
+    assert serializer.dump_json({'type': 'bar', 'val': 'x'}) == b'{"type":"bar","val":"x"}'
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load({'type': 'bar', 'val': 1})
+    assert exc_info.value.errors == [ErrorItem(message='1 is not of type "string"', instance_path='val')]
+
+
+def test_tagged_union__typed_dict_and_dataclass():
+    serializer = Serializer(Annotated[Union[FooDict, Bar], Discriminator('type')])
+
+    assert serializer.load({'type': 'foo', 'val': 1}) == {'type': 'foo', 'val': 1}
+    assert serializer.load({'type': 'bar', 'val': 'x'}) == Bar(type='bar', val='x')
+    assert serializer.dump({'type': 'foo', 'val': 1}) == {'type': 'foo', 'val': 1}
+    assert serializer.dump(Bar(type='bar', val='x')) == {'type': 'bar', 'val': 'x'}
+
+
+@dataclass
+class EventMeta:
+    event_kind: Literal['created']
+    version: int = 1
+
+
+@dataclass
+class Created:
+    meta: EventMeta
+    id: int
+
+
+@dataclass
+class DeletedMeta:
+    event_kind: Literal['deleted']
+
+
+@dataclass
+class Deleted:
+    meta: DeletedMeta
+
+
+def test_tagged_union__nested_discriminator():
+    serializer = Serializer(
+        Annotated[Union[Created, Deleted], Discriminator('meta.event_kind')],
+        camelcase_fields=True,
+    )
+    created = Created(meta=EventMeta(event_kind='created'), id=1)
+    raw = {'meta': {'eventKind': 'created', 'version': 1}, 'id': 1}
+
+    assert serializer.load(raw) == created
+    assert serializer.load_json('{"meta": {"eventKind": "deleted"}}') == Deleted(meta=DeletedMeta(event_kind='deleted'))
+    assert serializer.dump(created) == raw
+    assert serializer.dump_json(Deleted(meta=DeletedMeta(event_kind='deleted'))) == b'{"meta":{"eventKind":"deleted"}}'
+
+
+@pytest.mark.parametrize(
+    ['value', 'error'],
+    [
+        ({'id': 1}, ErrorItem(message='"eventKind" is a required property', instance_path='meta/eventKind')),
+        ({'meta': {}}, ErrorItem(message='"eventKind" is a required property', instance_path='meta/eventKind')),
+        (
+            {'meta': {'eventKind': 'updated'}},
+            ErrorItem(
+                message='"updated" is not one of ["created", "deleted"] discriminator values',
+                instance_path='meta/eventKind',
+            ),
+        ),
+    ],
+)
+def test_tagged_union__nested_discriminator__invalid(value, error):
+    serializer = Serializer(
+        Annotated[Union[Created, Deleted], Discriminator('meta.event_kind')],
+        camelcase_fields=True,
+    )
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load(value)
+    assert exc_info.value.errors == [error]
+
+
+def test_tagged_union__nested_discriminator__default_tag():
+    serializer = Serializer(Annotated[Union[Created, Deleted], Discriminator('meta.event_kind', default_tag='deleted')])
+    deleted = Deleted(meta=DeletedMeta(event_kind='deleted'))
+
+    assert serializer.load({}) == deleted
+    assert serializer.load({'meta': {}}) == deleted
+    assert serializer.load_json('{}') == deleted
+    assert serializer.load_json('{"meta": {}}') == deleted
+
+
+class MessageType(IntEnum):
+    PING = 1
+    DATA = 2
+
+
+@dataclass
+class Ping:
+    type: Literal[MessageType.PING]
+
+
+@dataclass
+class Data:
+    type: Literal[2]
+    payload: str
+
+
+@dataclass
+class Ack:
+    type: Literal[False]
+
+
+MessageT = Annotated[Union[Ping, Data, Ack], Discriminator('type')]
+
+
+def test_tagged_union__int_and_bool_discriminator():
+    serializer = Serializer(list[MessageT])
+    messages = [Ping(type=MessageType.PING), Data(type=2, payload='foo'), Ack(type=False)]
+    raw = [{'type': 1}, {'type': 2, 'payload': 'foo'}, {'type': False}]
+
+    assert serializer.load(raw) == messages
+    assert serializer.load_json('[{"type": 1}, {"type": 2, "payload": "foo"}, {"type": false}]') == messages
+    assert serializer.dump(messages) == raw
+    assert serializer.dump_json(messages) == b'[{"type":1},{"type":2,"payload":"foo"},{"type":false}]'
+
+
+@pytest.mark.parametrize(
+    ['value', 'error'],
+    [
+        ({'type': 3}, '3 is not one of [1, 2, False] discriminator values'),
+        ({'type': True}, 'True is not one of [1, 2, False] discriminator values'),
+        ({'type': 0}, '0 is not one of [1, 2, False] discriminator values'),
+        ({'type': '1'}, '"1" is not one of [1, 2, False] discriminator values'),
+        ({'type': 1.0}, '1.0 is not one of [1, 2, False] discriminator values'),
+    ],
+)
+def test_tagged_union__int_and_bool_discriminator__invalid(value, error):
+    serializer = Serializer(MessageT)
+    for load in (serializer.load, lambda v: serializer.load_json(json.dumps(v))):
+        with pytest.raises(SchemaValidationError) as exc_info:
+            load(value)
+        assert exc_info.value.errors == [ErrorItem(message=error, instance_path='type')]
+
+
+def test_tagged_union__int_discriminator__default_tag():
+    serializer = Serializer(Annotated[Union[Ping, Data], Discriminator('type', default_tag=MessageType.PING)])
+    assert serializer.load({}) == Ping(type=MessageType.PING)
+    assert serializer.load_json('{}') == Ping(type=MessageType.PING)
+
+
+def test_tagged_union__bool_and_int_discriminator_collision():
+    @dataclass
+    class Yes:
+        type: Literal[True]
+
+    with pytest.raises(RuntimeError) as exc_info:
+        Serializer(Annotated[Union[Ping, Yes], Discriminator('type')])
+    assert exc_info.value.args[0].startswith('Duplicate discriminator value "True"')
+
+
+@dataclass
+class Point:
+    x: int
+    y: int
+
+
+@dataclass
+class Circle:
+    center: Point
+    radius: float
+
+
+def _shape_tag(value: Any) -> Optional[str]:
+    if isinstance(value, dict):
+        return 'circle' if 'radius' in value else 'point' if 'x' in value else None
+    return type(value).__name__.lower()
+
+
+ShapeT = Annotated[
+    Union[Annotated[Point, Tag('point')], Annotated[Circle, Tag('circle')]],
+    Discriminator(_shape_tag),
+]
+
+
+def test_tagged_union__callable_discriminator():
+    serializer = Serializer(list[ShapeT])
+    shapes = [Point(x=1, y=2), Circle(center=Point(x=0, y=0), radius=1.5)]
+    raw = [{'x': 1, 'y': 2}, {'center': {'x': 0, 'y': 0}, 'radius': 1.5}]
+
+    assert serializer.load(raw) == shapes
+    assert serializer.load_json('[{"x": 1, "y": 2}, {"center": {"x": 0, "y": 0}, "radius": 1.5}]') == shapes
+    assert serializer.dump(shapes) == raw
+    assert serializer.dump_json(shapes) == b'[{"x":1,"y":2},{"center":{"x":0,"y":0},"radius":1.5}]'
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load([{'x': 1, 'y': 2}, {'y': 1}])
+    assert exc_info.value.errors == [
+        ErrorItem(message='None is not one of ["point", "circle"] discriminator values', instance_path='1')
+    ]
+
+
+def test_tagged_union__callable_discriminator__value_types():
+    serializer = Serializer(
+        Annotated[
+            Union[Annotated[int, Tag('int')], Annotated[list[int], Tag('list')]],
+            Discriminator(lambda value: type(value).__name__, default_tag='list'),
+        ]
+    )
+
+    assert serializer.load(1) == 1
+    assert serializer.load([1, 2]) == [1, 2]
+    assert serializer.dump([1, 2]) == [1, 2]
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load('1')
+    assert exc_info.value.errors == [
+        ErrorItem(message='"str" is not one of ["int", "list"] discriminator values', instance_path='')
+    ]
+
+
+def test_tagged_union__callable_discriminator__dump_function():
+    serializer = Serializer(
+        Annotated[
+            Union[Annotated[Point, Tag('point')], Annotated[Circle, Tag('circle')]],
+            Discriminator(
+                lambda value: value.get('kind'),
+                default_tag='point',
+                dump=lambda value: 'circle' if isinstance(value, Circle) else 'point',
+            ),
+        ]
+    )
+
+    assert serializer.load({'x': 1, 'y': 2}) == Point(x=1, y=2)
+    assert serializer.load({'kind': 'circle', 'center': {'x': 0, 'y': 0}, 'radius': 1}) == Circle(
+        center=Point(x=0, y=0), radius=1
+    )
+    assert serializer.dump(Circle(center=Point(x=0, y=0), radius=1)) == {'center': {'x': 0, 'y': 0}, 'radius': 1}
+
+
+def test_tagged_union__callable_discriminator__error():
+    def discriminator(value: Any) -> str:
+        raise ValueError('Unknown shape')
+
+    with pytest.raises(RuntimeError) as exc_info:
+        Serializer(Annotated[Union[Annotated[Point, Tag('point')], Circle], Discriminator(discriminator)])
+    assert exc_info.value.args[0].startswith('Variants of unions with callable discriminator must have Tag.')
+
+    serializer = Serializer(
+        Annotated[Union[Annotated[Point, Tag('point')], Annotated[Circle, Tag('circle')]], Discriminator(discriminator)]
+    )
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load({})
+    assert exc_info.value.errors == [ErrorItem(message='ValueError: Unknown shape', instance_path='')]
+
+
 def test_union_simple_types():
     serializer = Serializer(Union[int, str])
     assert serializer.dump(123) == 123
@@ -237,6 +555,43 @@ def test_union_simple_types():
     assert serializer.load('123') == '123'
 
 
+def test_dump_union__dispatched_by_value_type():
+    @dataclass
+    class A:
+        val: Decimal
+
+    serializer = Serializer(Union[str, int, Any, A, Decimal])
+
+    assert serializer.dump(A(val=Decimal('1.5'))) == {'val': '1.5'}
+    assert serializer.dump(Decimal('1.5')) == '1.5'
+    assert serializer.dump('1.5') == '1.5'
+    assert serializer.dump_json(A(val=Decimal('1.5'))) == b'{"val":"1.5"}'
+    assert serializer.dump_json(Decimal('1.5')) == b'"1.5"'
+    # values of the unknown types are dumped by the first variant that accepts them
+    assert serializer.dump(None) is None
+
+
+def test_dump_union__nested_types():
+    @dataclass
+    class A:
+        val: int
+
+    serializer = Serializer(Union[str, Optional[A], list[A], dict[str, A]])
+
+    assert serializer.dump([A(val=1)]) == [{'val': 1}]
+    assert serializer.dump({'a': A(val=1)}) == {'a': {'val': 1}}
+    assert serializer.dump(A(val=1)) == {'val': 1}
+    assert serializer.dump(None) is None
+    assert serializer.dump_json([A(val=1)]) == b'[{"val":1}]'
+
+
+def test_dump_union__ambiguous_types_are_tried_in_order():
+    serializer = Serializer(Union[Literal['a'], str])
+    assert serializer.dump('a') == 'a'
+    assert serializer.dump('b') == 'b'
+    assert serializer.dump_json('b') == b'"b"'
+
+
 def test_load_union():
     @dataclass
     class Foo:
@@ -270,3 +625,168 @@ def test_load_union_simple_types__invalid_type():
         serializer.load(123.0)
 
     assert exc_info.value.errors == [ErrorItem(message='123.0 is not of type "Union[int, str]"', instance_path='')]
+
+
+def test_load_union__smart_prefers_exact_type():
+    @dataclass
+    class WithFloat:
+        val: float
+
+    @dataclass
+    class WithInt:
+        val: int
+
+    assert Serializer(Union[WithFloat, WithInt]).load({'val': 1}) == WithFloat(val=1)
+
+    serializer = Serializer(Annotated[Union[WithFloat, WithInt], SmartUnion])
+    assert serializer.load({'val': 1}) == WithInt(val=1)
+    assert serializer.load({'val': 1.5}) == WithFloat(val=1.5)
+    assert serializer.load_json('{"val": 1}') == WithInt(val=1)
+    assert serializer.load_json('{"val": 1.5}') == WithFloat(val=1.5)
+
+
+def test_load_union__smart_simple_types():
+    serializer = Serializer(Annotated[Union[Decimal, int], SmartUnion])
+    assert type(serializer.load(1)) is int
+    assert serializer.load(Decimal('1.5')) == Decimal('1.5')
+    assert serializer.load('1.5') == Decimal('1.5')
+    assert type(Serializer(Union[Decimal, int]).load(1)) is Decimal
+
+
+def test_load_union__smart_falls_back_to_coercion():
+    serializer = Serializer(Annotated[Union[int, str], SmartUnion, Lax])
+    assert serializer.load('1') == '1'
+    assert serializer.load(1) == 1
+
+    serializer = Serializer(Annotated[Union[int, bool], SmartUnion, Lax])
+    assert serializer.load('1') == 1
+
+
+
+def test_load_union__smart_exact_pass_ignores_field_coercion():
+    @dataclass
+    class A:
+        x: Annotated[float, Lax]
+
+    @dataclass
+    class B:
+        x: int
+
+    serializer = Serializer(Annotated[Union[A, B], SmartUnion])
+    assert type(serializer.load({'x': 1})) is B
+    assert serializer.load({'x': 1.5}) == A(x=1.5)
+    assert serializer.load({'x': '1.5'}) == A(x=1.5)
+
+def test_load_union__smart_runs_post_init_once():
+    calls = []
+
+    @dataclass
+    class A:
+        x: int
+
+        def __post_init__(self):
+            calls.append('A')
+            raise ValueError('Q')
+
+    @dataclass
+    class B:
+        x: float
+
+        def __post_init__(self):
+            calls.append('B')
+
+    serializer = Serializer(Annotated[Union[Annotated[A, CallPostInit], Annotated[B, CallPostInit]], SmartUnion])
+    result = serializer.load({'x': 1})
+    assert calls == ['A', 'B']
+    assert type(result) is B
+
+    calls.clear()
+    serializer.load({'x': 1.5})
+    assert calls == ['B']
+
+
+@pytest.mark.parametrize('smart', [False, True])
+def test_load_union__invalid__closest_variant_errors(smart):
+    @dataclass
+    class A:
+        a: int
+        b: int
+
+    @dataclass
+    class B:
+        a: int
+        c: list[int]
+
+    serializer = Serializer(Annotated[Union[A, B], SmartUnion] if smart else Union[A, B])
+    value = {'a': 1, 'c': [1, 'x']}
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load(value)
+
+    union_error, *errors = exc_info.value.errors
+    assert union_error.message.startswith(f'{value} is not of type')
+    assert errors == [ErrorItem(message='"x" is not of type "integer"', instance_path='c/1')]
+
+
+
+def test_load_union__invalid__depth_ignores_slashes_in_keys():
+    @dataclass
+    class A:
+        y: list[int]
+
+    serializer = Serializer(Union[dict[str, int], A])
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load({'a/b/c/d': 'x', 'y': [1, 'z']})
+
+    union_error, *errors = exc_info.value.errors
+    assert union_error.instance_path == ''
+    assert errors == [ErrorItem(message='"z" is not of type "integer"', instance_path='y/1')]
+
+def test_load_union__invalid__same_depth_prefers_first_variant():
+    @dataclass
+    class A:
+        a: int
+        b: int
+
+    @dataclass
+    class B:
+        a: str
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Union[A, B]).load_json('{"a": null, "b": null}')
+
+    union_error, *errors = exc_info.value.errors
+    assert union_error.instance_path == ''
+    assert errors == [ErrorItem(message='None is not of type "integer"', instance_path='a')]
+
+
+def test_load_union__invalid__nested_path():
+    @dataclass
+    class A:
+        a: int
+
+    serializer = Serializer(dict[str, list[Union[A, int]]])
+    with pytest.raises(SchemaValidationError) as exc_info:
+        serializer.load({'key': [1, {'a': 'x'}]})
+
+    union_error, *errors = exc_info.value.errors
+    assert union_error.instance_path == 'key/1'
+    assert errors == [ErrorItem(message='"x" is not of type "integer"', instance_path='key/1/a')]
+
+
+def test_load_union__invalid__fewest_errors_at_same_depth():
+    @dataclass
+    class A:
+        a: int
+        b: int
+
+    @dataclass
+    class B:
+        a: int
+        b: str
+
+    with pytest.raises(SchemaValidationError) as exc_info:
+        Serializer(Union[A, B], collect_errors=True).load({'a': None, 'b': 'x'})
+
+    union_error, *errors = exc_info.value.errors
+    assert union_error.instance_path == ''
+    assert errors == [ErrorItem(message='None is not of type "integer"', instance_path='a')]
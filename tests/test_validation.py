@@ -1,14 +1,31 @@
 import uuid
 from dataclasses import dataclass
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from enum import Enum
-from typing import Annotated, Any, Literal, TypedDict, Union
+from typing import Annotated, Any, Literal, Optional, TypedDict, Union
 
 import pytest
 from serpyco_rs import SchemaValidationError, Serializer
 from serpyco_rs._impl import ErrorItem
-from serpyco_rs.metadata import CustomEncoder, Discriminator, Max, MaxLength, Min, MinLength
+from serpyco_rs.metadata import (
+    CustomEncoder,
+    DateFormat,
+    DecimalPlaces,
+    Discriminator,
+    ForbidDuplicates,
+    Max,
+    MaxDigits,
+    MaxLength,
+    Min,
+    MinLength,
+    Pattern,
+    RequireAware,
+    RequireNaive,
+    StringFormat,
+    Timestamp,
+    Validator,
+)
 
 
 def _check_errors(s: Serializer, value: Any, expected_errors: list[ErrorItem]):
@@ -30,6 +47,26 @@ def test_integer_validation(value, err):
     _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
 
 
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        (2**64 - 1, '18446744073709551615 is less than the minimum of 18446744073709551616'),
+        (2**128 + 1, f'{2**128 + 1} is greater than the maximum of {2**128}'),
+        (-(2**70), '-1180591620717411303424 is less than the minimum of 18446744073709551616'),
+    ],
+)
+def test_integer_validation__big_int(value, err):
+    s = Serializer(Annotated[int, Min(2**64), Max(2**128)])
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
+def test_integer_validation__big_int_in_bounds():
+    s = Serializer(Annotated[int, Min(-(2**100)), Max(2**128)])
+    assert s.load(2**128) == 2**128
+    assert s.load(-(2**99)) == -(2**99)
+    assert s.load_json(str(2**128)) == 2**128
+
+
 def test_integer_validation__invalid_type():
     s = Serializer(int)
     _check_errors(s, '1', [ErrorItem(message='"1" is not of type "integer"', instance_path='')])
@@ -53,6 +90,91 @@ def test_string_validation(value, err):
     _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
 
 
+@pytest.mark.parametrize('value', ['123', 'abc-42', '2024-01-01'])
+def test_string_pattern__matched__loaded(value):
+    s = Serializer(Annotated[str, Pattern(r'\d+$')])
+    assert s.load(value) == value
+
+
+@pytest.mark.parametrize('value', ['', 'abc', '42abc'])
+def test_string_pattern__not_matched__error(value):
+    s = Serializer(Annotated[str, Pattern(r'\d+$')])
+    _check_errors(s, value, [ErrorItem(message=f'"{value}" does not match "\\d+$"', instance_path='')])
+
+
+def test_string_pattern__nested__error_has_path():
+    @dataclass
+    class A:
+        code: Annotated[str, Pattern('^[A-Z]{3}$')]
+
+    s = Serializer(A)
+    assert s.load({'code': 'USD'}) == A(code='USD')
+    _check_errors(s, {'code': 'usd'}, [ErrorItem(message='"usd" does not match "^[A-Z]{3}$"', instance_path='code')])
+
+
+def test_string_pattern__invalid_regex__fail():
+    with pytest.raises(ValueError, match='Invalid pattern'):
+        Serializer(Annotated[str, Pattern('(')])
+
+
+@pytest.mark.parametrize(
+    ['fmt', 'value'],
+    [
+        ('email', 'user@example.com'),
+        ('email', 'first.last+tag@sub.example.org'),
+        ('email', 'user@[127.0.0.1]'),
+        ('uri', 'https://example.com/path?q=1#frag'),
+        ('uri', 'urn:isbn:0451450523'),
+        ('uri', 'https://example.com/%20space'),
+        ('hostname', 'example.com'),
+        ('hostname', 'localhost'),
+        ('ipv4', '192.168.0.1'),
+        ('ipv6', '::1'),
+        ('ipv6', '2001:db8::8a2e:370:7334'),
+        ('uuid', 'a9ab1d4b-8a7f-4e1c-9b43-5b2f1c2f3e4d'),
+        ('date', '2024-02-29'),
+        ('time', '12:30:00'),
+        ('date-time', '2024-02-29T12:30:00Z'),
+    ],
+)
+def test_string_format__valid__loaded(fmt, value):
+    s = Serializer(Annotated[str, StringFormat(fmt)])
+    assert s.load(value) == value
+
+
+@pytest.mark.parametrize(
+    ['fmt', 'value'],
+    [
+        ('email', 'user'),
+        ('email', '@example.com'),
+        ('email', 'user@'),
+        ('email', 'user..name@example.com'),
+        ('uri', 'example.com'),
+        ('uri', '1http://example.com'),
+        ('uri', 'https://example.com/with space'),
+        ('uri', 'https://example.com/%zz'),
+        ('hostname', '-example.com'),
+        ('hostname', 'exa_mple.com'),
+        ('hostname', 'a' * 64 + '.com'),
+        ('ipv4', '256.1.1.1'),
+        ('ipv4', '01.1.1.1'),
+        ('ipv6', '12345::'),
+        ('uuid', 'a9ab1d4b8a7f4e1c9b435b2f1c2f3e4d'),
+        ('date', '2023-02-29'),
+        ('time', '25:00:00'),
+        ('date-time', '2024-02-29'),
+    ],
+)
+def test_string_format__invalid__error(fmt, value):
+    s = Serializer(Annotated[str, StringFormat(fmt)])
+    _check_errors(s, value, [ErrorItem(message=f'"{value}" is not a valid "{fmt}"', instance_path='')])
+
+
+def test_string_format__unknown_format__fail():
+    with pytest.raises(ValueError, match='Unknown string format: "phone"'):
+        Serializer(Annotated[str, StringFormat('phone')])  # type: ignore[arg-type]
+
+
 def test_string_validation__invalid_type():
     s = Serializer(str)
     _check_errors(s, 1, [ErrorItem(message='1 is not of type "string"', instance_path='')])
@@ -100,6 +222,51 @@ def test_decimal_validation(value, err):
     assert e.value.errors == [ErrorItem(message=err, instance_path='')]
 
 
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        ('0.30000000000000000001', '0.30000000000000000001 is greater than the maximum of 0.3'),
+        ('0.09999999999999999999', '0.09999999999999999999 is less than the minimum of 0.1'),
+        ('NaN', 'NaN is not a finite number'),
+        (Decimal('-Infinity'), '-Infinity is not a finite number'),
+    ],
+)
+def test_decimal_validation__exact_bounds(value, err):
+    s = Serializer(Annotated[Decimal, Min(Decimal('0.1')), Max(0.3)])
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        ('123.45', None),
+        ('123.450000', None),
+        ('0.00', None),
+        ('100000', '100000 has more than 5 digits'),
+        ('1E+5', '1E+5 has more than 5 digits'),
+        ('1.234', '1.234 has more than 2 decimal places'),
+        ('0.001', '0.001 has more than 2 decimal places'),
+        ('NaN', 'NaN is not a finite number'),
+        ('Infinity', 'Infinity is not a finite number'),
+    ],
+)
+def test_decimal_validation__digits(value, err):
+    s = Serializer(Annotated[Decimal, MaxDigits(5), DecimalPlaces(2)])
+    if err is None:
+        assert s.load(value) == Decimal(value)
+    else:
+        _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
+def test_decimal_validation__digits_after_rounding():
+    s = Serializer(Annotated[Decimal, MaxDigits(3), DecimalPlaces(1, rounding='ROUND_HALF_UP')])
+    assert s.load('12.34') == Decimal('12.3')
+    _check_errors(s, '999.96', [ErrorItem(message='1000.0 has more than 3 digits', instance_path='')])
+    _check_errors(
+        s, 'Infinity', [ErrorItem(message='Infinity cannot be rounded to 1 decimal places', instance_path='')]
+    )
+
+
 def test_decimal_validation__invalid_type():
     s = Serializer(Decimal)
     with pytest.raises(SchemaValidationError) as e:
@@ -273,6 +440,116 @@ def test_tuple_validation__invalid_length():
     assert e.value.errors == [ErrorItem(message="[1, 'foo', 3] has more than 2 items", instance_path='')]
 
 
+def test_variable_length_tuple_validation__invalid_item_type():
+    s = Serializer(tuple[int, ...])
+    _check_errors(s, [1, 'a'], [ErrorItem(message='"a" is not of type "integer"', instance_path='1')])
+
+
+def test_variable_length_tuple_validation__invalid_length():
+    s = Serializer(Annotated[tuple[int, ...], MinLength(1), MaxLength(2)])
+    _check_errors(s, [], [ErrorItem(message='[] has less than 1 items', instance_path='')])
+    _check_errors(s, [1, 2, 3], [ErrorItem(message='[1, 2, 3] has more than 2 items', instance_path='')])
+
+
+def test_set_validation__invalid_type():
+    s = Serializer(set[int])
+    _check_errors(s, 'foo', [ErrorItem(message='"foo" is not of type "array"', instance_path='')])
+
+
+def test_set_validation__invalid_item_type():
+    s = Serializer(set[int])
+    _check_errors(s, [1, 'a'], [ErrorItem(message='"a" is not of type "integer"', instance_path='1')])
+
+
+def test_set_validation__forbid_duplicates():
+    s = Serializer(Annotated[set[int], ForbidDuplicates])
+    assert s.load([1, 2]) == {1, 2}
+    _check_errors(s, [1, 2, 1], [ErrorItem(message='[1, 2, 1] has non-unique elements', instance_path='')])
+    with pytest.raises(SchemaValidationError) as e:
+        s.load_json('[1,2,1]')
+    assert e.value.errors == [ErrorItem(message='[1, 2, 1] has non-unique elements', instance_path='')]
+
+
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        ('2024-01-01T00:00:00Z', '"2024-01-01T00:00:00Z" is not of type "timestamp"'),
+        (True, 'True is not of type "timestamp"'),
+        (10**20, '100000000000000000000 is out of the datetime range'),
+        (-62135596801, '-62135596801 is out of the datetime range'),
+        (float('inf'), 'inf is out of the datetime range'),
+    ],
+)
+def test_datetime_timestamp_validation(value, err):
+    s = Serializer(Annotated[datetime, Timestamp()])
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
+
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        ('2024-01-01', '"2024-01-01" is not of type "timestamp"'),
+        (1704153601, '1704153601 is not a valid date timestamp'),
+        (-62135683200, '-62135683200 is not a valid date timestamp'),
+    ],
+)
+def test_date_timestamp_validation(value, err):
+    s = Serializer(Annotated[date, Timestamp()])
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+@pytest.mark.parametrize(
+    ['tz_policy', 'value', 'err'],
+    [
+        (RequireAware, '2024-01-02T03:04:00', '"2024-01-02T03:04:00" is not an aware datetime'),
+        (RequireNaive, '2024-01-02T03:04:00Z', '"2024-01-02T03:04:00Z" is not a naive datetime'),
+    ],
+)
+def test_datetime_tz_policy_validation(tz_policy, value, err):
+    @dataclass
+    class A:
+        created_at: list[Annotated[datetime, tz_policy]]
+
+    s = Serializer(A)
+    _check_errors(s, {'created_at': [value]}, [ErrorItem(message=err, instance_path='created_at/0')])
+
+
+@pytest.mark.parametrize(
+    ['annotation', 'value', 'err'],
+    [
+        (Annotated[date, DateFormat('%d.%m.%Y')], '2024-01-02', '"2024-01-02" does not match format "%d.%m.%Y"'),
+        (Annotated[date, DateFormat('%d.%m.%Y')], '31.02.2024', '"31.02.2024" does not match format "%d.%m.%Y"'),
+        (Annotated[date, DateFormat('%d.%m.%Y')], 1, '1 is not of type "date"'),
+        (Annotated[time, DateFormat('%H:%M')], '03:04:05', '"03:04:05" does not match format "%H:%M"'),
+        (Annotated[datetime, DateFormat('%Y %H')], '2024 03', '"2024 03" does not match format "%Y %H"'),
+        (Annotated[datetime, DateFormat('%Y-%m-%d %H:%M')], None, 'None is not of type "datetime"'),
+    ],
+)
+def test_date_format_validation(annotation, value, err):
+    s = Serializer(annotation)
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
+@pytest.mark.parametrize(
+    ['value', 'err'],
+    [
+        ('foo', '"foo" is not a valid duration: duration_invalid_number'),
+        ('P1X', '"P1X" is not a valid duration: duration_invalid_date_unit'),
+        ('P1Y', '"P1Y" is not a valid duration: years and months are not supported'),
+        ('-P2MT1H', '"-P2MT1H" is not a valid duration: years and months are not supported'),
+        ('P999999999DT24H', '"P999999999DT24H" is not a valid duration: duration_days_too_large'),
+        (True, 'True is not of type "duration"'),
+        (None, 'None is not of type "duration"'),
+        (1e30, '1e+30 is out of the duration range'),
+        (float('nan'), 'nan is out of the duration range'),
+        (10**30, '1000000000000000000000000000000 is out of the duration range'),
+    ],
+)
+def test_timedelta_validation(value, err):
+    s = Serializer(timedelta)
+    _check_errors(s, value, [ErrorItem(message=err, instance_path='')])
+
+
 def test_bytes_validation__invalid_type():
     s = Serializer(bytes)
     with pytest.raises(SchemaValidationError) as e:
@@ -354,3 +631,119 @@ def test_instance_path():
         {'bar': [{'foo': [{'a': 1}]}, {'foo': [{'b': 1}]}]},
         [ErrorItem(message='"a" is a required property', instance_path='bar/1/foo/0/a')],
     )
+
+
+def _check_positive(value: int) -> None:
+    if value <= 0:
+        raise ValueError('must be positive')
+
+
+@dataclass
+class _Range:
+    start: Annotated[int, Validator(_check_positive)]
+    end: int
+
+
+def _check_range(value: _Range) -> None:
+    if value.end <= value.start:
+        raise ValueError('end must be greater than start')
+
+
+def test_validator():
+    s = Serializer(list[Annotated[_Range, Validator(_check_range)]])
+
+    assert s.load([{'start': 1, 'end': 2}]) == [_Range(start=1, end=2)]
+    _check_errors(
+        s, [{'start': 1, 'end': 2}, {'start': 0, 'end': 2}], [ErrorItem('ValueError: must be positive', '1/start')]
+    )
+    _check_errors(
+        s, [{'start': 2, 'end': 1}], [ErrorItem('ValueError: end must be greater than start', '0')]
+    )
+    with pytest.raises(SchemaValidationError):
+        s.load_json(b'[{"start": 2, "end": 1}]')
+
+
+
+@pytest.mark.parametrize(
+    't',
+    [Annotated[Optional[int], Validator(_check_positive)], Optional[Annotated[int, Validator(_check_positive)]]],
+)
+def test_validator__optional_skips_none(t):
+    s = Serializer(t)
+
+    assert s.load(None) is None
+    assert s.load_json('null') is None
+    assert s.load(1) == 1
+    _check_errors(s, 0, [ErrorItem('ValueError: must be positive', '')])
+
+def test_validator__runs_after_custom_encoder():
+    s = Serializer(Annotated[str, CustomEncoder[str, str](deserialize=str.strip), Validator(_check_not_empty)])
+
+    assert s.load(' a ') == 'a'
+    _check_errors(s, '  ', [ErrorItem('ValueError: must not be empty', '')])
+
+
+def _check_not_empty(value: str) -> None:
+    if not value:
+        raise ValueError('must not be empty')
+
+
+def test_validator__other_exceptions_are_propagated():
+    def validator(value: int) -> None:
+        raise TypeError('boom')
+
+    with pytest.raises(TypeError, match='boom'):
+        Serializer(Annotated[int, Validator(validator)]).load(1)
+
+
+def test_validator__typed_dict_field_and_json_schema():
+    class A(TypedDict):
+        value: Annotated[int, Validator(_check_positive), Min(1)]
+
+    s = Serializer(A)
+
+    _check_errors(s, {'value': 0}, [ErrorItem('0 is less than the minimum of 1', 'value')])
+    assert s.get_json_schema()['components']['schemas'][
+        'tests.test_validation.test_validator__typed_dict_field_and_json_schema.<locals>.A'
+    ]['properties'] == {'value': {'type': 'integer', 'minimum': 1}}
+
+
+@Validator(_check_range)
+@dataclass
+class _ValidatedRange:
+    start: int
+    end: int
+
+
+@dataclass
+class _Schedule:
+    ranges: list[_ValidatedRange]
+    current: Optional[_ValidatedRange] = None
+
+
+def test_validator__class_decorator():
+    s = Serializer(_Schedule)
+
+    assert s.load({'ranges': [{'start': 1, 'end': 2}]}) == _Schedule(ranges=[_ValidatedRange(start=1, end=2)])
+    error = ErrorItem('ValueError: end must be greater than start', 'current')
+    _check_errors(s, {'ranges': [], 'current': {'start': 2, 'end': 1}}, [error])
+    with pytest.raises(SchemaValidationError) as e:
+        s.load_json(b'{"ranges": [{"start": 1, "end": 2}, {"start": 2, "end": 1}]}')
+    assert e.value.errors == [ErrorItem('ValueError: end must be greater than start', 'ranges/1')]
+
+
+def test_validator__class_decorator__inherited():
+    def check_end(value: _ValidatedRange) -> None:
+        if value.end > 10:
+            raise ValueError('end must not be greater than 10')
+
+    @Validator(check_end)
+    @dataclass
+    class ShortRange(_ValidatedRange):
+        pass
+
+    s = Serializer(ShortRange)
+
+    _check_errors(s, {'start': 2, 'end': 1}, [ErrorItem('ValueError: end must be greater than start', '')])
+    _check_errors(s, {'start': 2, 'end': 11}, [ErrorItem('ValueError: end must not be greater than 10', '')])
+    assert Serializer(_ValidatedRange).load({'start': 2, 'end': 11}) == _ValidatedRange(start=2, end=11)